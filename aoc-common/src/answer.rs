@@ -0,0 +1,65 @@
+use std::fmt::Display;
+
+use serde::Serialize;
+
+/// Answer of a part, kept as a number when it is one so `--json` reports it as a number
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Num(i128),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Num(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(n: $int) -> Self {
+                    Answer::Num(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i32, i64, isize, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answer_json() {
+        assert_eq!(
+            serde_json::to_string(&Answer::from(520usize)).unwrap(),
+            "520"
+        );
+        assert_eq!(serde_json::to_string(&Answer::from(-3isize)).unwrap(), "-3");
+        assert_eq!(
+            serde_json::to_string(&Answer::from("CMZ")).unwrap(),
+            r#""CMZ""#
+        );
+        assert_eq!(Answer::from(u64::MAX).to_string(), u64::MAX.to_string());
+    }
+}
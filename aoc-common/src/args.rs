@@ -0,0 +1,160 @@
+use std::collections::{HashMap, HashSet};
+use std::env;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::{AocError, STDIN_PATH};
+
+/// Options that are followed by a value, like `--part 2`
+const VALUE_OPTIONS: &[&str] = &[
+    "--part", "--delay", "--knots", "--ppm", "--rounds", "--image", "--repeat", "--report",
+];
+
+/// Command-line arguments shared by every day
+pub struct Args {
+    positional: Vec<String>,
+    flags: HashSet<String>,
+    options: HashMap<String, String>,
+}
+
+impl Args {
+    pub fn from_env() -> Result<Self, AocError> {
+        Self::parse(env::args().skip(1))
+    }
+
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self, AocError> {
+        let mut positional = Vec::new();
+        let mut flags = HashSet::new();
+        let mut options = HashMap::new();
+
+        let mut args_it = args.into_iter();
+        while let Some(arg) = args_it.next() {
+            if VALUE_OPTIONS.contains(&arg.as_str()) {
+                let value = args_it.next().ok_or_else(|| {
+                    AocError::Parse(format!("Missing value for option '{}'", arg))
+                })?;
+                options.insert(arg, value);
+            } else if arg.starts_with("--") {
+                flags.insert(arg);
+            } else {
+                positional.push(arg);
+            }
+        }
+
+        let args = Args {
+            positional,
+            flags,
+            options,
+        };
+
+        // Validate the requested part early, so a typo doesn't silently run nothing
+        if let Some(part) = args.option("--part") {
+            if part != "1" && part != "2" {
+                return Err(AocError::Parse(format!(
+                    "Invalid part '{}', expected 1 or 2",
+                    part
+                )));
+            }
+        }
+
+        Ok(args)
+    }
+
+    /// Checks if a flag such as `--json` was given
+    pub fn flag(&self, name: &str) -> bool {
+        self.flags.contains(name)
+    }
+
+    /// Returns the value given to an option such as `--part`
+    pub fn option(&self, name: &str) -> Option<&str> {
+        self.options.get(name).map(String::as_str)
+    }
+
+    /// Returns the value given to an option parsed as `T`, failing if it can't be parsed
+    pub fn parsed_option<T: FromStr>(&self, name: &str) -> Result<Option<T>, AocError> {
+        self.option(name)
+            .map(|value| {
+                value.parse().map_err(|_| {
+                    AocError::Parse(format!("Invalid value '{}' for option '{}'", value, name))
+                })
+            })
+            .transpose()
+    }
+
+    /// Checks if the given part should be computed, which is always the case without `--part`
+    pub fn runs_part(&self, part: u32) -> bool {
+        self.option("--part").is_none_or(|p| p == part.to_string())
+    }
+
+    /// Returns the input path given as the first positional argument, or `default` if there's none.
+    /// The path `-` is passed through as is, meaning the input is read from stdin
+    pub fn input_path<P: AsRef<Path>>(&self, default: P) -> io::Result<PathBuf> {
+        let path = self
+            .positional
+            .first()
+            .map(PathBuf::from)
+            .unwrap_or_else(|| default.as_ref().to_path_buf());
+
+        // Make sure the input file exists before anyone tries to parse it
+        if path != Path::new(STDIN_PATH) && !path.is_file() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("Input file '{}' not found", path.display()),
+            ));
+        }
+
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args, AocError> {
+        Args::parse(args.iter().map(|a| a.to_string()))
+    }
+
+    #[test]
+    fn flags_and_positionals() {
+        let args = parse(&["--json", "-"]).unwrap();
+
+        assert!(args.flag("--json"));
+        assert!(!args.flag("--other"));
+        assert_eq!(
+            args.input_path("unused").unwrap(),
+            PathBuf::from(STDIN_PATH)
+        );
+    }
+
+    #[test]
+    fn part_option() {
+        let args = parse(&["--part", "2", "-"]).unwrap();
+        assert!(!args.runs_part(1));
+        assert!(args.runs_part(2));
+        assert_eq!(
+            args.input_path("unused").unwrap(),
+            PathBuf::from(STDIN_PATH)
+        );
+
+        let args = parse(&[]).unwrap();
+        assert!(args.runs_part(1) && args.runs_part(2));
+
+        assert!(matches!(parse(&["--part", "3"]), Err(AocError::Parse(_))));
+        assert!(matches!(parse(&["--part"]), Err(AocError::Parse(_))));
+    }
+
+    #[test]
+    fn parsed_options() {
+        let args = parse(&["--delay", "250"]).unwrap();
+        assert_eq!(args.parsed_option::<u64>("--delay").unwrap(), Some(250));
+        assert_eq!(args.parsed_option::<u64>("--part").unwrap(), None);
+
+        let args = parse(&["--delay", "soon"]).unwrap();
+        assert!(matches!(
+            args.parsed_option::<u64>("--delay"),
+            Err(AocError::Parse(_))
+        ));
+    }
+}
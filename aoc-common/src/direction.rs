@@ -0,0 +1,75 @@
+use std::str::FromStr;
+
+use crate::AocError;
+
+/// One of the four orthogonal directions on a grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    /// Every direction, in the order grids list a cell's neighbours
+    pub const fn all() -> [Direction; 4] {
+        [
+            Direction::Up,
+            Direction::Down,
+            Direction::Left,
+            Direction::Right,
+        ]
+    }
+
+    /// Offset `(row, column)` of a single step in this direction, with rows going down
+    pub const fn delta(&self) -> (isize, isize) {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+            Direction::Right => (0, 1),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "U" | "u" => Ok(Direction::Up),
+            "D" | "d" => Ok(Direction::Down),
+            "L" | "l" => Ok(Direction::Left),
+            "R" | "r" => Ok(Direction::Right),
+            other => Err(AocError::Parse(format!(
+                "Can't convert string '{}' to direction",
+                other
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deltas_cancel_out() {
+        let (rows, cols) = Direction::all()
+            .iter()
+            .map(Direction::delta)
+            .fold((0, 0), |(rows, cols), (dr, dc)| (rows + dr, cols + dc));
+        assert_eq!((rows, cols), (0, 0));
+        assert_eq!(Direction::Up.delta(), (-1, 0));
+        assert_eq!(Direction::Right.delta(), (0, 1));
+    }
+
+    #[test]
+    fn parse_either_case() {
+        assert_eq!("U".parse::<Direction>().unwrap(), Direction::Up);
+        assert_eq!("l".parse::<Direction>().unwrap(), Direction::Left);
+        assert!("UL".parse::<Direction>().is_err());
+        assert!("x".parse::<Direction>().is_err());
+    }
+}
@@ -0,0 +1,47 @@
+use std::fmt::Display;
+use std::io;
+use std::num::ParseIntError;
+
+#[derive(Debug)]
+pub enum AocError {
+    Io(io::Error),
+    Parse(String),
+    MissingField(&'static str),
+    /// A computation gave a number too large for its type
+    Overflow(String),
+    /// A position or index outside what it refers to
+    OutOfRange(String),
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocError::Io(e) => write!(f, "I/O error: {}", e),
+            AocError::Parse(msg) => write!(f, "Parse error: {}", msg),
+            AocError::MissingField(field) => write!(f, "Expected field '{}' not found", field),
+            AocError::Overflow(msg) => write!(f, "Overflow: {}", msg),
+            AocError::OutOfRange(msg) => write!(f, "Out of range: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AocError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for AocError {
+    fn from(e: io::Error) -> Self {
+        AocError::Io(e)
+    }
+}
+
+impl From<ParseIntError> for AocError {
+    fn from(e: ParseIntError) -> Self {
+        AocError::Parse(e.to_string())
+    }
+}
@@ -0,0 +1,254 @@
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use crate::{AocError, Direction, Point};
+
+/// Offsets to the north, south, west and east neighbours of a cell
+const NEIGHBOUR_OFFSETS_4: [(isize, isize); 4] = {
+    let [up, down, left, right] = Direction::all();
+    [up.delta(), down.delta(), left.delta(), right.delta()]
+};
+
+/// Offsets to the four neighbours above, followed by the diagonal ones
+const NEIGHBOUR_OFFSETS_8: [(isize, isize); 8] = [
+    (-1, 0),
+    (1, 0),
+    (0, -1),
+    (0, 1),
+    (-1, -1),
+    (-1, 1),
+    (1, -1),
+    (1, 1),
+];
+
+/// Rectangular grid of cells, indexed by `(row, column)`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(rows: Vec<Vec<T>>) -> Result<Self, AocError> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |r| r.len());
+        if width == 0 {
+            return Err(AocError::Parse("Grid is empty".to_string()));
+        }
+        if let Some(i) = rows.iter().position(|r| r.len() != width) {
+            return Err(AocError::Parse(format!(
+                "Grid row {} has {} cells, expected {}",
+                i + 1,
+                rows[i].len(),
+                width
+            )));
+        }
+
+        Ok(Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Builds a grid from the lines of `s`, mapping each character (and its position) to a cell
+    pub fn from_str_with<F>(s: &str, mut f: F) -> Result<Self, AocError>
+    where
+        F: FnMut((usize, usize), char) -> Result<T, AocError>,
+    {
+        let rows = s
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(j, c)| f((i, j), c))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::new(rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row < self.height && col < self.width {
+            self.cells.get(row * self.width + col)
+        } else {
+            None
+        }
+    }
+
+    /// Iterates over the positions north, south, west and east of a cell that are inside the grid
+    pub fn neighbors4(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.neighbors_with(&NEIGHBOUR_OFFSETS_4, row, col)
+    }
+
+    /// Iterates over the positions around a cell, diagonals included, that are inside the grid
+    pub fn neighbors8(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.neighbors_with(&NEIGHBOUR_OFFSETS_8, row, col)
+    }
+
+    fn neighbors_with(
+        &self,
+        offsets: &'static [(isize, isize)],
+        row: usize,
+        col: usize,
+    ) -> impl Iterator<Item = (usize, usize)> {
+        let (width, height) = (self.width, self.height);
+        offsets.iter().filter_map(move |&(row_offset, col_offset)| {
+            let row = row.checked_add_signed(row_offset)?;
+            let col = col.checked_add_signed(col_offset)?;
+            (row < height && col < width).then_some((row, col))
+        })
+    }
+}
+
+/// Grid holding each character of the lines as is
+impl FromStr for Grid<char> {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::from_str_with(s, |_, c| Ok(c))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        assert!(
+            row < self.height && col < self.width,
+            "Position out of the grid"
+        );
+        &self.cells[row * self.width + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        assert!(
+            row < self.height && col < self.width,
+            "Position out of the grid"
+        );
+        &mut self.cells[row * self.width + col]
+    }
+}
+
+impl<T> Index<Point<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point<usize>) -> &Self::Output {
+        &self[(p.y, p.x)]
+    }
+}
+
+impl<T> IndexMut<Point<usize>> for Grid<T> {
+    fn index_mut(&mut self, p: Point<usize>) -> &mut Self::Output {
+        &mut self[(p.y, p.x)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digit_grid(s: &str) -> Grid<u32> {
+        Grid::from_str_with(s, |_, c| {
+            c.to_digit(10)
+                .ok_or_else(|| AocError::Parse(format!("'{}' is not a digit", c)))
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn build_and_index() {
+        let grid = digit_grid("123\n456");
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 2)], 6);
+        assert_eq!(grid.get(0, 1), Some(&2));
+        assert_eq!(grid.get(2, 0), None);
+        assert_eq!(grid.get(0, 3), None);
+    }
+
+    #[test]
+    fn char_grid() {
+        let grid: Grid<char> = "ab\ncd".parse().unwrap();
+        assert_eq!(
+            grid,
+            Grid::new(vec![vec!['a', 'b'], vec!['c', 'd']]).unwrap()
+        );
+        assert!("ab\nc".parse::<Grid<char>>().is_err());
+    }
+
+    #[test]
+    fn reject_bad_grids() {
+        assert!(matches!(Grid::<u32>::new(vec![]), Err(AocError::Parse(_))));
+        assert!(matches!(
+            Grid::new(vec![vec![1, 2], vec![3]]),
+            Err(AocError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn neighbors_on_corners_edges_and_center() {
+        let grid = digit_grid("123\n456\n789");
+        let neighbors = |r, c| grid.neighbors4(r, c).collect::<Vec<_>>();
+
+        // Corners
+        assert_eq!(neighbors(0, 0), vec![(1, 0), (0, 1)]);
+        assert_eq!(neighbors(0, 2), vec![(1, 2), (0, 1)]);
+        assert_eq!(neighbors(2, 0), vec![(1, 0), (2, 1)]);
+        assert_eq!(neighbors(2, 2), vec![(1, 2), (2, 1)]);
+
+        // Edges
+        assert_eq!(neighbors(0, 1), vec![(1, 1), (0, 0), (0, 2)]);
+        assert_eq!(neighbors(1, 0), vec![(0, 0), (2, 0), (1, 1)]);
+        assert_eq!(neighbors(1, 2), vec![(0, 2), (2, 2), (1, 1)]);
+        assert_eq!(neighbors(2, 1), vec![(1, 1), (2, 0), (2, 2)]);
+
+        // Center
+        assert_eq!(neighbors(1, 1), vec![(0, 1), (2, 1), (1, 0), (1, 2)]);
+    }
+
+    #[test]
+    fn neighbors_of_single_cell() {
+        let grid = digit_grid("5");
+        assert_eq!(grid.neighbors4(0, 0).count(), 0);
+        assert_eq!(grid.neighbors8(0, 0).count(), 0);
+    }
+
+    #[test]
+    fn neighbors_with_diagonals() {
+        let grid = digit_grid("123\n456\n789");
+        let neighbors = |r, c| grid.neighbors8(r, c).collect::<Vec<_>>();
+
+        assert_eq!(neighbors(0, 0), vec![(1, 0), (0, 1), (1, 1)]);
+        assert_eq!(
+            neighbors(2, 1),
+            vec![(1, 1), (2, 0), (2, 2), (1, 0), (1, 2)]
+        );
+        assert_eq!(
+            neighbors(1, 1),
+            vec![
+                (0, 1),
+                (2, 1),
+                (1, 0),
+                (1, 2),
+                (0, 0),
+                (0, 2),
+                (2, 0),
+                (2, 2)
+            ]
+        );
+    }
+}
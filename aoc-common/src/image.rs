@@ -0,0 +1,39 @@
+/// Encodes an image as a binary PPM, where `pixel(row, col)` gives the RGB color of each pixel
+/// and each pixel is drawn as a `scale` by `scale` square
+pub fn encode_ppm<F>(width: usize, height: usize, scale: usize, pixel: F) -> Vec<u8>
+where
+    F: Fn(usize, usize) -> [u8; 3],
+{
+    let mut ppm = format!("P6\n{} {}\n255\n", width * scale, height * scale).into_bytes();
+    for row in 0..height {
+        for _ in 0..scale {
+            for col in 0..width {
+                let color = pixel(row, col);
+                for _ in 0..scale {
+                    ppm.extend(color);
+                }
+            }
+        }
+    }
+
+    ppm
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scaled_pixels() {
+        let ppm = encode_ppm(2, 1, 2, |_, col| [col as u8, 0, 7]);
+
+        let header = b"P6\n4 2\n255\n";
+        assert_eq!(&ppm[..header.len()], header);
+        assert_eq!(
+            &ppm[header.len()..],
+            [[0, 0, 7], [0, 0, 7], [1, 0, 7], [1, 0, 7]]
+                .repeat(2)
+                .concat()
+        );
+    }
+}
@@ -0,0 +1,201 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::Path;
+
+mod answer;
+mod args;
+mod direction;
+mod error;
+mod grid;
+mod image;
+mod point;
+mod progress;
+mod report;
+mod solution;
+mod timing;
+
+pub use answer::Answer;
+pub use args::Args;
+pub use direction::Direction;
+pub use error::AocError;
+pub use grid::Grid;
+pub use image::encode_ppm;
+pub use point::Point;
+pub use progress::Progress;
+pub use report::{DayReport, PartReport};
+pub use solution::{
+    describe_timing, plain_part, print_results, print_single_result, run_main, run_parts, solve,
+    solve_str, time_part, DayResult, PartResult, Solution,
+};
+pub use timing::{format_millis, PhaseTimer, TimingStats};
+
+/// Input path that stands for the standard input
+pub const STDIN_PATH: &str = "-";
+
+/// First bytes of every gzip stream
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Opens the input for reading, using the standard input if the path is `-`. Gzipped inputs,
+/// told apart by a `.gz` extension or their first bytes, are decompressed on the fly
+pub fn open_input<P: AsRef<Path>>(p: P) -> io::Result<Box<dyn BufRead>> {
+    let p = p.as_ref();
+    if p == Path::new(STDIN_PATH) {
+        return decompress_if_gzip(BufReader::new(io::stdin()), false);
+    }
+
+    // Open input file
+    let input = File::open(p)?;
+    let has_gz_extension = p.extension().is_some_and(|ext| ext == "gz");
+    decompress_if_gzip(BufReader::new(input), has_gz_extension)
+}
+
+fn decompress_if_gzip<R: BufRead + 'static>(
+    mut input: R,
+    has_gz_extension: bool,
+) -> io::Result<Box<dyn BufRead>> {
+    if has_gz_extension || input.fill_buf()?.starts_with(&GZIP_MAGIC) {
+        return open_gzip(input);
+    }
+
+    Ok(Box::new(input))
+}
+
+#[cfg(feature = "gzip")]
+fn open_gzip<R: BufRead + 'static>(input: R) -> io::Result<Box<dyn BufRead>> {
+    Ok(Box::new(BufReader::new(flate2::bufread::GzDecoder::new(
+        input,
+    ))))
+}
+
+#[cfg(not(feature = "gzip"))]
+fn open_gzip<R: BufRead + 'static>(_input: R) -> io::Result<Box<dyn BufRead>> {
+    Err(io::Error::new(
+        io::ErrorKind::InvalidData,
+        "Reading a gzipped input requires the 'gzip' feature",
+    ))
+}
+
+/// Reads the whole input and returns its lines, without line terminators
+pub fn read_lines<P: AsRef<Path>>(p: P) -> io::Result<Vec<String>> {
+    open_input(p)?.lines().collect()
+}
+
+/// Reads the whole input into a string
+pub fn read_to_string<P: AsRef<Path>>(p: P) -> io::Result<String> {
+    let mut input_buf = open_input(p)?;
+
+    let mut input_str = String::new();
+    input_buf.read_to_string(&mut input_str)?;
+
+    Ok(input_str)
+}
+
+/// Reads the whole input into a string, with leading and trailing whitespace removed
+pub fn read_to_string_trimmed<P: AsRef<Path>>(p: P) -> io::Result<String> {
+    Ok(read_to_string(p)?.trim().to_string())
+}
+
+/// Splits the input into records separated by blank lines, whatever its line endings are. Records
+/// keep their own line breaks, and several blank lines in a row don't make empty records
+pub fn split_records(s: &str) -> Vec<&str> {
+    split_numbered_records(s)
+        .into_iter()
+        .map(|(_, record)| record)
+        .collect()
+}
+
+/// Same as `split_records`, along with the 1-based line number each record starts at
+pub fn split_numbered_records(s: &str) -> Vec<(usize, &str)> {
+    let mut records = Vec::new();
+    let mut record_start = None;
+    let mut record_end = 0;
+
+    let mut line_start = 0;
+    for (i, line) in s.split_inclusive('\n').enumerate() {
+        if line.trim().is_empty() {
+            // A blank line closes the current record, if there's one
+            if let Some((line_number, start)) = record_start.take() {
+                records.push((line_number, &s[start..record_end]));
+            }
+        } else {
+            record_start.get_or_insert((i + 1, line_start));
+            record_end = line_start + line.trim_end_matches(['\r', '\n']).len();
+        }
+        line_start += line.len();
+    }
+    if let Some((line_number, start)) = record_start {
+        records.push((line_number, &s[start..record_end]));
+    }
+
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::env;
+    use std::fs;
+    use std::path::PathBuf;
+
+    /// Writes `contents` to a file in the temporary directory, returning its path
+    fn temp_file(name: &str, contents: &[u8]) -> PathBuf {
+        let path = env::temp_dir().join(format!("aoc-common-{}-{}", std::process::id(), name));
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn plain_input() {
+        let path = temp_file("plain.in", b"1\n2\n");
+        assert_eq!(read_lines(&path).unwrap(), vec!["1", "2"]);
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn records() {
+        assert_eq!(split_records("1\n2\n\n3\n"), vec!["1\n2", "3"]);
+        assert_eq!(
+            split_records("1\r\n2\r\n\r\n3\n\n4\r\n \n\n5"),
+            vec!["1\r\n2", "3", "4", "5"]
+        );
+        assert_eq!(split_records("\n\n1\n\n\n\n2\n\n"), vec!["1", "2"]);
+        assert!(split_records("").is_empty());
+        assert!(split_records("\r\n\r\n").is_empty());
+        assert_eq!(
+            split_numbered_records("\n1\r\n2\r\n\r\n\n3\n"),
+            vec![(2, "1\r\n2"), (6, "3")]
+        );
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn gzipped_input() {
+        use flate2::write::GzEncoder;
+        use flate2::Compression;
+        use std::io::Write;
+
+        let plain = include_str!("../../inputs/day01.in");
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(plain.as_bytes()).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        // Detected either by the extension or by the contents
+        for name in ["day01.in.gz", "day01.in"] {
+            let path = temp_file(name, &compressed);
+            assert_eq!(read_to_string(&path).unwrap(), plain);
+            fs::remove_file(path).unwrap();
+        }
+    }
+
+    #[cfg(not(feature = "gzip"))]
+    #[test]
+    fn gzipped_input_needs_feature() {
+        let path = temp_file("gzip-disabled.in.gz", &[0x1f, 0x8b, 0x08, 0x00]);
+        assert_eq!(
+            read_to_string(&path).unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,108 @@
+use std::num::TryFromIntError;
+use std::ops::{Add, AddAssign};
+
+/// 2D coordinate. On grids `x` is the column and `y` is the row
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+}
+
+impl Point<isize> {
+    pub fn manhattan(&self, other: &Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev(&self, other: &Self) -> usize {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Unit step (or no step, per axis) that moves this point towards `other`
+    pub fn signum_towards(&self, other: &Self) -> Self {
+        Point::new((other.x - self.x).signum(), (other.y - self.y).signum())
+    }
+}
+
+impl Point<usize> {
+    pub fn manhattan(&self, other: &Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev(&self, other: &Self) -> usize {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl TryFrom<Point<isize>> for Point<usize> {
+    type Error = TryFromIntError;
+
+    fn try_from(p: Point<isize>) -> Result<Self, Self::Error> {
+        Ok(Point::new(p.x.try_into()?, p.y.try_into()?))
+    }
+}
+
+impl TryFrom<Point<usize>> for Point<isize> {
+    type Error = TryFromIntError;
+
+    fn try_from(p: Point<usize>) -> Result<Self, Self::Error> {
+        Ok(Point::new(p.x.try_into()?, p.y.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distances() {
+        let a = Point::new(-1_isize, 2);
+        let b = Point::new(3_isize, -1);
+        assert_eq!(a.manhattan(&b), 7);
+        assert_eq!(a.chebyshev(&b), 4);
+
+        let a = Point::new(1_usize, 5);
+        let b = Point::new(4_usize, 3);
+        assert_eq!(a.manhattan(&b), 5);
+        assert_eq!(a.chebyshev(&b), 3);
+    }
+
+    #[test]
+    fn signum_towards() {
+        let origin = Point::new(0_isize, 0);
+        assert_eq!(origin.signum_towards(&Point::new(2, -1)), Point::new(1, -1));
+        assert_eq!(origin.signum_towards(&Point::new(0, 5)), Point::new(0, 1));
+        assert_eq!(origin.signum_towards(&origin), Point::new(0, 0));
+    }
+
+    #[test]
+    fn signed_unsigned_conversions() {
+        let p: Point<usize> = Point::new(3_isize, 4).try_into().unwrap();
+        assert_eq!(p, Point::new(3, 4));
+        assert!(Point::<usize>::try_from(Point::new(-1_isize, 4)).is_err());
+
+        let p: Point<isize> = Point::new(3_usize, 4).try_into().unwrap();
+        assert_eq!(p, Point::new(3, 4));
+        assert!(Point::<isize>::try_from(Point::new(usize::MAX, 0)).is_err());
+    }
+}
@@ -0,0 +1,59 @@
+use crate::Args;
+
+/// Progress bar for long computations. It's only drawn when `--progress` is given and the crate
+/// is built with the `progress` feature, otherwise every method does nothing
+pub struct Progress {
+    #[cfg(feature = "progress")]
+    bar: Option<indicatif::ProgressBar>,
+}
+
+impl Progress {
+    /// Creates a progress bar going up to `len`, labelled with `message`
+    #[cfg(feature = "progress")]
+    pub fn new(args: &Args, len: u64, message: &'static str) -> Self {
+        let bar = args.flag("--progress").then(|| {
+            let style = indicatif::ProgressStyle::with_template(
+                "{msg} [{elapsed_precise}] {wide_bar} {pos}/{len}",
+            )
+            .expect("Invalid progress bar template");
+
+            indicatif::ProgressBar::new(len)
+                .with_style(style)
+                .with_message(message)
+        });
+
+        Progress { bar }
+    }
+
+    /// Creates a progress bar going up to `len`, labelled with `message`
+    #[cfg(not(feature = "progress"))]
+    pub fn new(args: &Args, _len: u64, _message: &'static str) -> Self {
+        if args.flag("--progress") {
+            eprintln!("Progress bars require the 'progress' feature, ignoring '--progress'");
+        }
+
+        Progress {}
+    }
+
+    pub fn set_position(&self, _position: u64) {
+        #[cfg(feature = "progress")]
+        if let Some(bar) = &self.bar {
+            bar.set_position(_position);
+        }
+    }
+
+    pub fn inc(&self, _delta: u64) {
+        #[cfg(feature = "progress")]
+        if let Some(bar) = &self.bar {
+            bar.inc(_delta);
+        }
+    }
+
+    /// Removes the progress bar from the terminal
+    pub fn finish(&self) {
+        #[cfg(feature = "progress")]
+        if let Some(bar) = &self.bar {
+            bar.finish_and_clear();
+        }
+    }
+}
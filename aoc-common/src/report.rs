@@ -0,0 +1,68 @@
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct PartReport<T: Serialize> {
+    pub ms: f64,
+    pub answer: T,
+}
+
+/// Machine-readable results of a day, printed with `--json`
+#[derive(Debug, Serialize)]
+pub struct DayReport<A: Serialize, B: Serialize> {
+    pub day: u32,
+    pub parse_ms: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<PartReport<A>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<PartReport<B>>,
+}
+
+impl<A: Serialize, B: Serialize> DayReport<A, B> {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("Failed to serialize report")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_json_layout() {
+        let report = DayReport {
+            day: 12,
+            parse_ms: 1.5,
+            part1: Some(PartReport {
+                ms: 0.5,
+                answer: 31,
+            }),
+            part2: Some(PartReport {
+                ms: 0.25,
+                answer: "CRT",
+            }),
+        };
+
+        assert_eq!(
+            report.to_json(),
+            r#"{"day":12,"parse_ms":1.5,"part1":{"ms":0.5,"answer":31},"part2":{"ms":0.25,"answer":"CRT"}}"#
+        );
+    }
+
+    #[test]
+    fn report_json_skips_missing_parts() {
+        let report: DayReport<u32, u32> = DayReport {
+            day: 11,
+            parse_ms: 1.5,
+            part1: None,
+            part2: Some(PartReport {
+                ms: 0.25,
+                answer: 7,
+            }),
+        };
+
+        assert_eq!(
+            report.to_json(),
+            r#"{"day":11,"parse_ms":1.5,"part2":{"ms":0.25,"answer":7}}"#
+        );
+    }
+}
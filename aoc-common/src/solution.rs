@@ -0,0 +1,350 @@
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use crate::{
+    format_millis, read_to_string, Answer, AocError, Args, DayReport, PartReport, PhaseTimer,
+    TimingStats,
+};
+
+/// A day's puzzle, split into parsing the input and solving each part from it
+pub trait Solution {
+    /// Day of the puzzle, as shown in reports
+    const DAY: u32;
+    /// Input used when none is given on the command line
+    const INPUT_PATH: &'static str;
+    /// Descriptions printed before each part's answer
+    const PART1_LABEL: &'static str;
+    const PART2_LABEL: &'static str;
+
+    type Input;
+
+    fn parse(s: &str) -> Result<Self::Input, AocError>;
+    fn part1(input: &Self::Input) -> Result<Answer, AocError>;
+    fn part2(input: &Self::Input) -> Result<Answer, AocError>;
+
+    /// Extra output printed after a part's answer in text mode, like the state it ended in
+    fn part1_details(_input: &Self::Input) -> Result<Option<String>, AocError> {
+        Ok(None)
+    }
+
+    fn part2_details(_input: &Self::Input) -> Result<Option<String>, AocError> {
+        Ok(None)
+    }
+}
+
+/// Answer of a single part, with how long it took
+pub struct PartResult {
+    pub label: &'static str,
+    pub timing: TimingStats,
+    pub answer: Answer,
+    pub details: Option<String>,
+}
+
+/// Answers of both parts of a day, with how long parsing and each part took
+#[derive(Debug, Clone)]
+pub struct DayResult {
+    pub part1: Answer,
+    pub part2: Answer,
+    pub parse_time: Duration,
+    pub part1_time: Duration,
+    pub part2_time: Duration,
+}
+
+/// Reads the input at `path` and solves both parts, timing each step. Reading the file doesn't
+/// count towards the parse time
+pub fn solve<S: Solution, P: AsRef<Path>>(path: P) -> Result<DayResult, AocError> {
+    let input_str = read_to_string(path)?;
+
+    let t = Instant::now();
+    let input = S::parse(&input_str)?;
+    let parse_time = t.elapsed();
+
+    let t = Instant::now();
+    let part1 = S::part1(&input)?;
+    let part1_time = t.elapsed();
+
+    let t = Instant::now();
+    let part2 = S::part2(&input)?;
+    let part2_time = t.elapsed();
+
+    Ok(DayResult {
+        part1,
+        part2,
+        parse_time,
+        part1_time,
+        part2_time,
+    })
+}
+
+/// Parses the given input, then solves both parts
+pub fn solve_str<S: Solution>(input: &str) -> Result<(String, String), AocError> {
+    let input = S::parse(input)?;
+
+    Ok((S::part1(&input)?.to_string(), S::part2(&input)?.to_string()))
+}
+
+/// Parses the input given on the command line and solves the requested parts, printing the
+/// results and how long each step took
+pub fn run_main<S: Solution>(args: &Args) -> Result<(), AocError> {
+    let input_path = args.input_path(S::INPUT_PATH)?;
+    let input_str = read_to_string(input_path)?;
+
+    // Parse the input and time it, without the file IO, like `solve` does
+    let t0 = Instant::now();
+    let input = S::parse(&input_str)?;
+    let parse_time = t0.elapsed();
+
+    run_parts::<S, _>(args, &input, parse_time, plain_part::<S>)
+}
+
+/// Computes a part with `Solution::part1` or `part2`, for `run_parts` on days that don't break
+/// their parts down any further
+pub fn plain_part<S: Solution>(
+    part: u32,
+    input: &S::Input,
+    _timer: &mut PhaseTimer,
+) -> Result<Answer, AocError> {
+    if part == 1 {
+        S::part1(input)
+    } else {
+        S::part2(input)
+    }
+}
+
+/// Solves the requested parts from an already parsed input and prints the results. Each part is
+/// computed by `run_part`, given its number and a timer to record its phases in, which lets a day
+/// show its progress or time its phases with `--verbose`
+pub fn run_parts<S, F>(
+    args: &Args,
+    input: &S::Input,
+    parse_time: Duration,
+    mut run_part: F,
+) -> Result<(), AocError>
+where
+    S: Solution,
+    F: FnMut(u32, &S::Input, &mut PhaseTimer) -> Result<Answer, AocError>,
+{
+    let mut timers = [PhaseTimer::disabled(), PhaseTimer::disabled()];
+    let mut results = [None, None];
+    for (part, label) in [(1, S::PART1_LABEL), (2, S::PART2_LABEL)] {
+        if !args.runs_part(part) {
+            continue;
+        }
+
+        let index = part as usize - 1;
+        let timer = &mut timers[index];
+        results[index] = Some(time_part(args, label, || {
+            // Only the phases of the last run are kept with `--repeat`
+            *timer = PhaseTimer::from_args(args);
+            run_part(part, input, timer)
+        })?);
+    }
+    let [mut part1, mut part2] = results;
+
+    // Details are only shown in text mode, and aren't part of the timings
+    if !args.flag("--json") {
+        if let Some(part) = &mut part1 {
+            part.details = S::part1_details(input)?;
+        }
+        if let Some(part) = &mut part2 {
+            part.details = S::part2_details(input)?;
+        }
+    }
+
+    print_results(args, S::DAY, parse_time, part1, part2);
+
+    // The breakdowns would break the JSON output
+    if !args.flag("--json") {
+        timers[0].print_breakdown("Part 1 phases");
+        timers[1].print_breakdown("Part 2 phases");
+    }
+
+    Ok(())
+}
+
+/// Runs a part and times it, as many times as `--repeat` asks for
+pub fn time_part<A, F>(args: &Args, label: &'static str, mut f: F) -> Result<PartResult, AocError>
+where
+    A: Into<Answer>,
+    F: FnMut() -> Result<A, AocError>,
+{
+    let repeat = args.parsed_option("--repeat")?.unwrap_or(1);
+    if repeat == 0 {
+        return Err(AocError::Parse(
+            "Parts have to run at least once with '--repeat'".to_string(),
+        ));
+    }
+
+    // Every run gives the same answer, so only the last one is kept
+    let mut durations = Vec::with_capacity(repeat);
+    let mut answer = None;
+    for _ in 0..repeat {
+        let t = Instant::now();
+        answer = Some(f()?);
+        durations.push(t.elapsed());
+    }
+
+    Ok(PartResult {
+        label,
+        timing: TimingStats::from_durations(&durations),
+        answer: answer.expect("Parts run at least once").into(),
+        details: None,
+    })
+}
+
+/// Describes how long a part took, with the spread of the timings if it ran several times
+pub fn describe_timing(timing: &TimingStats) -> String {
+    if timing.runs == 1 {
+        return format!("Took {:.6}ms", timing.mean_ms);
+    }
+
+    format!(
+        "Took {:.6}ms on average over {} runs (min {:.6}ms, max {:.6}ms)",
+        timing.mean_ms, timing.runs, timing.min_ms, timing.max_ms
+    )
+}
+
+/// Prints the results of a day, as JSON with `--json` or as text otherwise
+pub fn print_results(
+    args: &Args,
+    day: u32,
+    parse_time: Duration,
+    part1: Option<PartResult>,
+    part2: Option<PartResult>,
+) {
+    if args.flag("--json") {
+        print_json(day, parse_time, part1, part2);
+        return;
+    }
+
+    println!(
+        "Parsing the input took {:.6}ms\n",
+        format_millis(parse_time)
+    );
+    for (part_number, part) in [(1, part1), (2, part2)] {
+        if let Some(part) = part {
+            print_part(&format!("Part {}", part_number), &part);
+        }
+    }
+}
+
+/// Prints the result of a day-specific mode that replaces both parts with a single answer,
+/// reported as part 1 with `--json`
+pub fn print_single_result(
+    args: &Args,
+    day: u32,
+    parse_time: Duration,
+    heading: &str,
+    part: PartResult,
+) {
+    if args.flag("--json") {
+        print_json(day, parse_time, Some(part), None);
+        return;
+    }
+
+    println!(
+        "Parsing the input took {:.6}ms\n",
+        format_millis(parse_time)
+    );
+    print_part(heading, &part);
+}
+
+fn print_json(
+    day: u32,
+    parse_time: Duration,
+    part1: Option<PartResult>,
+    part2: Option<PartResult>,
+) {
+    let to_report = |part: PartResult| PartReport {
+        ms: part.timing.mean_ms,
+        answer: part.answer,
+    };
+    let report = DayReport {
+        day,
+        parse_ms: format_millis(parse_time),
+        part1: part1.map(to_report),
+        part2: part2.map(to_report),
+    };
+    println!("{}", report.to_json());
+}
+
+fn print_part(heading: &str, part: &PartResult) {
+    // Answers drawn over several lines start on their own line
+    let answer = part.answer.to_string();
+    let separator = if answer.contains('\n') { "\n" } else { " " };
+    println!(
+        "{}:\n{}\n{}:{}{}",
+        heading,
+        describe_timing(&part.timing),
+        part.label,
+        separator,
+        answer
+    );
+    if let Some(details) = &part.details {
+        println!("{}", details);
+    }
+    println!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u32 = 0;
+        const INPUT_PATH: &'static str = "unused";
+        const PART1_LABEL: &'static str = "Sum";
+        const PART2_LABEL: &'static str = "Doubled sum";
+
+        type Input = Vec<u32>;
+
+        fn parse(s: &str) -> Result<Self::Input, AocError> {
+            Ok(s.split_whitespace()
+                .map(str::parse)
+                .collect::<Result<_, _>>()?)
+        }
+
+        fn part1(input: &Self::Input) -> Result<Answer, AocError> {
+            Ok(input.iter().sum::<u32>().into())
+        }
+
+        fn part2(input: &Self::Input) -> Result<Answer, AocError> {
+            Ok((2 * input.iter().sum::<u32>()).into())
+        }
+    }
+
+    #[test]
+    fn solution_parts() {
+        let input = Sum::parse("1 2 3").unwrap();
+        assert_eq!(Sum::part1(&input).unwrap(), Answer::Num(6));
+        assert_eq!(Sum::part2(&input).unwrap(), Answer::Num(12));
+        assert!(matches!(Sum::parse("1 two"), Err(AocError::Parse(_))));
+
+        let answers = solve_str::<Sum>("4 5").unwrap();
+        assert_eq!(answers, ("9".to_string(), "18".to_string()));
+    }
+
+    #[test]
+    fn repeated_parts() {
+        let args = Args::parse(["--repeat", "3"].map(String::from)).unwrap();
+        let mut runs = 0;
+        let part = time_part(&args, "Runs", || {
+            runs += 1;
+            Ok(runs)
+        })
+        .unwrap();
+
+        assert_eq!(runs, 3);
+        assert_eq!(part.answer, Answer::Num(3));
+        assert_eq!(part.timing.runs, 3);
+        assert!(describe_timing(&part.timing).contains("over 3 runs"));
+
+        let args = Args::parse(["--repeat", "0"].map(String::from)).unwrap();
+        assert!(matches!(
+            time_part(&args, "Runs", || Ok(0u32)),
+            Err(AocError::Parse(_))
+        ));
+    }
+}
@@ -0,0 +1,134 @@
+use std::time::{Duration, Instant};
+
+use crate::Args;
+
+/// Converts a duration to fractional milliseconds, for printing
+pub fn format_millis(d: Duration) -> f64 {
+    d.as_secs_f64() * 1000.0
+}
+
+/// Summary of the durations of repeated runs of the same computation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimingStats {
+    pub runs: usize,
+    pub mean_ms: f64,
+    pub min_ms: f64,
+    pub max_ms: f64,
+}
+
+impl TimingStats {
+    /// Summarizes the given durations, which must not be empty
+    pub fn from_durations(durations: &[Duration]) -> Self {
+        assert!(!durations.is_empty(), "No durations to summarize");
+
+        let total: Duration = durations.iter().sum();
+        TimingStats {
+            runs: durations.len(),
+            mean_ms: format_millis(total) / durations.len() as f64,
+            min_ms: format_millis(*durations.iter().min().unwrap()),
+            max_ms: format_millis(*durations.iter().max().unwrap()),
+        }
+    }
+}
+
+/// Durations of the phases a computation goes through, for a finer breakdown than the whole
+/// parts. A disabled timer runs the phases without reading the clock
+#[derive(Debug, Clone, Default)]
+pub struct PhaseTimer {
+    enabled: bool,
+    phases: Vec<(&'static str, Duration)>,
+}
+
+impl PhaseTimer {
+    pub fn new(enabled: bool) -> Self {
+        PhaseTimer {
+            enabled,
+            phases: Vec::new(),
+        }
+    }
+
+    pub fn disabled() -> Self {
+        Self::new(false)
+    }
+
+    /// Timer that is only enabled with `--verbose`
+    pub fn from_args(args: &Args) -> Self {
+        Self::new(args.flag("--verbose"))
+    }
+
+    /// Runs a phase, recording how long it took under `name` if the timer is enabled
+    pub fn time<T, F: FnOnce() -> T>(&mut self, name: &'static str, f: F) -> T {
+        if !self.enabled {
+            return f();
+        }
+
+        let t = Instant::now();
+        let result = f();
+        self.phases.push((name, t.elapsed()));
+
+        result
+    }
+
+    /// Every phase recorded so far, in the order they ran
+    pub fn phases(&self) -> &[(&'static str, Duration)] {
+        &self.phases
+    }
+
+    /// Prints the recorded phases indented under `title`, if there are any
+    pub fn print_breakdown(&self, title: &str) {
+        if self.phases.is_empty() {
+            return;
+        }
+
+        println!("{}:", title);
+        for (name, duration) in &self.phases {
+            println!("  {}: {:.6}ms", name, format_millis(*duration));
+        }
+        println!();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn millis_of_known_durations() {
+        assert_eq!(format_millis(Duration::from_micros(1500)), 1.5);
+        assert_eq!(format_millis(Duration::from_millis(2)), 2.0);
+        assert!((format_millis(Duration::new(1, 250_000)) - 1000.25).abs() < 1e-9);
+    }
+
+    #[test]
+    fn stats_of_repeated_runs() {
+        let durations = [2, 1, 6].map(Duration::from_millis);
+        assert_eq!(
+            TimingStats::from_durations(&durations),
+            TimingStats {
+                runs: 3,
+                mean_ms: 3.0,
+                min_ms: 1.0,
+                max_ms: 6.0
+            }
+        );
+
+        let single = TimingStats::from_durations(&[Duration::from_micros(500)]);
+        assert_eq!(
+            (single.mean_ms, single.min_ms, single.max_ms),
+            (0.5, 0.5, 0.5)
+        );
+    }
+
+    #[test]
+    fn phase_timer() {
+        let mut timer = PhaseTimer::new(true);
+        assert_eq!(timer.time("first", || 1 + 1), 2);
+        timer.time("second", || ());
+        let names: Vec<_> = timer.phases().iter().map(|(name, _)| *name).collect();
+        assert_eq!(names, ["first", "second"]);
+
+        let mut timer = PhaseTimer::disabled();
+        assert_eq!(timer.time("first", || 3), 3);
+        assert!(timer.phases().is_empty());
+    }
+}
@@ -0,0 +1,278 @@
+use std::cmp::Reverse;
+use std::io::{self, BufRead};
+use std::path::Path;
+
+use aoc_common::{
+    read_to_string, solve, split_numbered_records, Answer, AocError, DayResult, Solution,
+};
+
+/// Parses a single calorie count, pointing at its 1-based line number when it's not one
+fn parse_calories(line_number: usize, line: &str) -> io::Result<u64> {
+    line.parse().map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "Line {}: invalid calorie count '{}' ({})",
+                line_number, line, e
+            ),
+        )
+    })
+}
+
+pub fn parse_str(input: &str, do_sort: bool) -> io::Result<Vec<Vec<u64>>> {
+    // Each elf is a record, with one item per line, ignoring surrounding whitespace
+    let mut elves_calories = split_numbered_records(input)
+        .into_iter()
+        .map(|(first_line, record)| {
+            record
+                .lines()
+                .enumerate()
+                .map(|(i, line)| parse_calories(first_line + i, line.trim()))
+                .collect::<io::Result<Vec<u64>>>()
+        })
+        .collect::<io::Result<Vec<_>>>()?;
+
+    if do_sort {
+        elves_calories.sort_by_cached_key(|calories| Reverse(calories.iter().sum::<u64>()));
+    }
+
+    Ok(elves_calories)
+}
+
+pub fn parse_input<T: AsRef<Path>>(filename: T, do_sort: bool) -> io::Result<Vec<Vec<u64>>> {
+    parse_str(&read_to_string(filename)?, do_sort)
+}
+
+/// Sums the calories carried by each elf, in input order
+pub fn elf_totals(elves_calories: &[Vec<u64>]) -> Vec<u64> {
+    elves_calories
+        .iter()
+        .map(|calories| calories.iter().sum())
+        .collect()
+}
+
+/// Finds the elf carrying the most calories, as its 1-based position in the input and its total
+pub fn max_elf(elves_calories: &[Vec<u64>]) -> (usize, u64) {
+    let mut max = (0, 0);
+    for (i, total) in elf_totals(elves_calories).into_iter().enumerate() {
+        if max.0 == 0 || total > max.1 {
+            max = (i + 1, total);
+        }
+    }
+
+    max
+}
+
+pub fn part1(elves_calories: &[Vec<u64>]) -> u64 {
+    max_elf(elves_calories).1
+}
+
+/// Sums the `n` largest of the elf totals given by `elf_totals`, in any order
+pub fn top_n_sum(totals: &[u64], n: usize) -> u64 {
+    let mut totals = totals.to_vec();
+
+    // Fewer elves than requested, so all of them are in the top n
+    if n >= totals.len() {
+        return totals.iter().sum();
+    }
+    if n == 0 {
+        return 0;
+    }
+
+    // Partition so the n largest totals come first, without a full sort
+    totals.select_nth_unstable_by(n - 1, |x, y| y.cmp(x));
+    totals[..n].iter().sum()
+}
+
+/// Finds the three largest elf totals (descending) in a single pass over the input, without
+/// keeping the elves in memory like `part2` does
+pub fn top3_streaming<R: BufRead>(r: R) -> io::Result<[u64; 3]> {
+    let mut top3 = [0; 3];
+    let mut push_total = |total: u64| {
+        if total > top3[2] {
+            top3[2] = total;
+            top3.sort_unstable_by(|x, y| y.cmp(x));
+        }
+    };
+
+    let mut current_total = 0;
+    for (i, line) in r.lines().enumerate() {
+        let line = line?;
+        let line = line.trim();
+
+        // An empty line closes the current elf
+        if line.is_empty() {
+            push_total(current_total);
+            current_total = 0;
+            continue;
+        }
+
+        current_total += parse_calories(i + 1, line)?;
+    }
+    push_total(current_total);
+
+    Ok(top3)
+}
+
+pub fn part2(elves_calories: &[Vec<u64>]) -> u64 {
+    top_n_sum(&elf_totals(elves_calories), 3)
+}
+
+pub const INPUT_PATH: &str = "inputs/day01.in";
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+    const INPUT_PATH: &'static str = INPUT_PATH;
+    const PART1_LABEL: &'static str = "Max calories";
+    const PART2_LABEL: &'static str = "Sum of three largest max calories";
+
+    type Input = Vec<Vec<u64>>;
+
+    fn parse(s: &str) -> Result<Self::Input, AocError> {
+        Ok(parse_str(s, false)?)
+    }
+
+    fn part1(elves_calories: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part1(elves_calories).into())
+    }
+
+    fn part2(elves_calories: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part2(elves_calories).into())
+    }
+
+    fn part1_details(elves_calories: &Self::Input) -> Result<Option<String>, AocError> {
+        let (elf_index, _) = max_elf(elves_calories);
+        Ok(Some(format!("Carried by elf #{}", elf_index)))
+    }
+}
+
+/// Solves both parts against the committed input
+pub fn run() -> Result<DayResult, AocError> {
+    solve::<Day01, _>(INPUT_PATH)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::solve_str;
+
+    const SAMPLE: &str = include_str!("../../../inputs/day01_test.in");
+    const SAMPLE_CRLF: &str = include_str!("../../../inputs/day01_test_crlf.in");
+
+    #[test]
+    fn part1_sample() {
+        let elves_calories = parse_str(SAMPLE, true).unwrap();
+        assert_eq!(part1(&elves_calories), 24000);
+    }
+
+    #[test]
+    fn part2_sample() {
+        let elves_calories = parse_str(SAMPLE, false).unwrap();
+        assert_eq!(part2(&elves_calories), 45000);
+        assert_eq!(
+            top3_streaming(SAMPLE.as_bytes())
+                .unwrap()
+                .iter()
+                .sum::<u64>(),
+            45000
+        );
+    }
+
+    #[test]
+    fn trailing_blank_line_and_spaces() {
+        let input = "1000 \n2000\n\n3000  \n\n";
+        let elves_calories = parse_str(input, false).unwrap();
+        assert_eq!(elves_calories, vec![vec![1000, 2000], vec![3000]]);
+        assert_eq!(part2(&elves_calories), 6000);
+        assert_eq!(top3_streaming(input.as_bytes()).unwrap(), [3000, 3000, 0]);
+    }
+
+    #[test]
+    fn crlf_input() {
+        let elves_calories = parse_str(SAMPLE_CRLF, false).unwrap();
+        assert_eq!(elves_calories, parse_str(SAMPLE, false).unwrap());
+        assert_eq!(part2(&elves_calories), 45000);
+        assert_eq!(
+            top3_streaming(SAMPLE_CRLF.as_bytes()).unwrap(),
+            top3_streaming(SAMPLE.as_bytes()).unwrap()
+        );
+    }
+
+    #[test]
+    fn totals_in_input_order() {
+        let elves_calories = parse_str(SAMPLE, false).unwrap();
+        assert_eq!(
+            elf_totals(&elves_calories),
+            vec![6000, 4000, 11000, 24000, 10000]
+        );
+    }
+
+    #[test]
+    fn max_elf_in_file_order() {
+        let elves_calories = parse_str(SAMPLE, false).unwrap();
+        assert_eq!(max_elf(&elves_calories), (4, 24000));
+        assert_eq!(part1(&elves_calories), 24000);
+
+        let elves_calories = parse_str("5\n\n7\n\n7", false).unwrap();
+        assert_eq!(max_elf(&elves_calories), (2, 7));
+    }
+
+    #[test]
+    fn mixed_line_endings() {
+        let input = "1000\r\n2000\n\r\n3000\n\n\n4000\r\n";
+        let elves_calories = parse_str(input, false).unwrap();
+        assert_eq!(
+            elves_calories,
+            vec![vec![1000, 2000], vec![3000], vec![4000]]
+        );
+    }
+
+    #[test]
+    fn top_n_unsorted() {
+        let totals = elf_totals(&parse_str(SAMPLE, false).unwrap());
+        assert_eq!(top_n_sum(&totals, 0), 0);
+        assert_eq!(top_n_sum(&totals, 1), 24000);
+        assert_eq!(top_n_sum(&totals, 3), 45000);
+        assert_eq!(top_n_sum(&totals, 5), 55000);
+    }
+
+    #[test]
+    fn top_n_with_two_elves() {
+        let input = "1000\n2000\n\n3000";
+        let elves_calories = parse_str(input, true).unwrap();
+        assert_eq!(top_n_sum(&elf_totals(&elves_calories), 3), 6000);
+        assert_eq!(part2(&elves_calories), 6000);
+    }
+
+    #[test]
+    fn streaming_matches_sorted() {
+        let elves_calories = parse_str(SAMPLE, true).unwrap();
+        let sorted_top3 = elf_totals(&elves_calories[..3]);
+        assert_eq!(top3_streaming(SAMPLE.as_bytes()).unwrap(), sorted_top3[..]);
+    }
+
+    #[test]
+    fn invalid_line_number() {
+        let input = "1000\n2000\n\n3000\nabc\n\n4000";
+        let err = parse_str(input, false).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert!(err
+            .to_string()
+            .starts_with("Line 5: invalid calorie count 'abc'"));
+
+        let err = top3_streaming("1000\r\n\r\n-2000\r\n".as_bytes()).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Line 3: invalid calorie count '-2000'"));
+    }
+
+    #[test]
+    fn empty_input() {
+        assert_eq!(
+            solve_str::<Day01>("").unwrap(),
+            ("0".to_string(), "0".to_string())
+        );
+    }
+}
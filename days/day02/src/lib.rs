@@ -0,0 +1,405 @@
+use std::path::Path;
+
+use aoc_common::{read_to_string, solve, Answer, AocError, DayResult, Solution};
+use phf::phf_map;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RPSShape {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RPSAction {
+    Lose,
+    Draw,
+    Win,
+}
+
+/// A weapon in a rock-paper-scissors style game
+pub trait Shape: Copy + PartialEq {
+    /// Points awarded for playing this shape
+    fn score(&self) -> u64;
+
+    fn defeats(&self, other: &Self) -> bool;
+
+    /// The outcome of playing this shape against `other`
+    fn outcome_vs(&self, other: &Self) -> RPSAction {
+        if self == other {
+            RPSAction::Draw
+        } else if self.defeats(other) {
+            RPSAction::Win
+        } else {
+            RPSAction::Lose
+        }
+    }
+}
+
+/// The shape to play against `opponent` to get the desired outcome
+pub fn shape_for_outcome(opponent: &RPSShape, action: &RPSAction) -> RPSShape {
+    match action {
+        RPSAction::Lose => opponent.beats(),
+        RPSAction::Draw => *opponent,
+        RPSAction::Win => opponent.loses_to(),
+    }
+}
+
+/// Score of a round: the shape played plus the outcome bonus
+pub fn round_score<S: Shape>(my_shape: &S, opponent: &S) -> u64 {
+    my_shape.score() + my_shape.outcome_vs(opponent).score()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExtendedShape {
+    Rock,
+    Paper,
+    Scissors,
+    Lizard,
+    Spock,
+}
+
+/// Every (winner, loser) pair of Rock-Paper-Scissors-Lizard-Spock
+const LIZARD_SPOCK_RULES: [(ExtendedShape, ExtendedShape); 10] = [
+    (ExtendedShape::Scissors, ExtendedShape::Paper),
+    (ExtendedShape::Paper, ExtendedShape::Rock),
+    (ExtendedShape::Rock, ExtendedShape::Lizard),
+    (ExtendedShape::Lizard, ExtendedShape::Spock),
+    (ExtendedShape::Spock, ExtendedShape::Scissors),
+    (ExtendedShape::Scissors, ExtendedShape::Lizard),
+    (ExtendedShape::Lizard, ExtendedShape::Paper),
+    (ExtendedShape::Paper, ExtendedShape::Spock),
+    (ExtendedShape::Spock, ExtendedShape::Rock),
+    (ExtendedShape::Rock, ExtendedShape::Scissors),
+];
+
+impl RPSShape {
+    /// The shape this one defeats
+    pub fn beats(&self) -> RPSShape {
+        match self {
+            RPSShape::Rock => RPSShape::Scissors,
+            RPSShape::Paper => RPSShape::Rock,
+            RPSShape::Scissors => RPSShape::Paper,
+        }
+    }
+
+    /// The shape that defeats this one
+    pub fn loses_to(&self) -> RPSShape {
+        match self {
+            RPSShape::Rock => RPSShape::Paper,
+            RPSShape::Paper => RPSShape::Scissors,
+            RPSShape::Scissors => RPSShape::Rock,
+        }
+    }
+}
+
+impl Shape for RPSShape {
+    fn score(&self) -> u64 {
+        match self {
+            RPSShape::Rock => 1,
+            RPSShape::Paper => 2,
+            RPSShape::Scissors => 3,
+        }
+    }
+
+    fn defeats(&self, other: &Self) -> bool {
+        self.beats() == *other
+    }
+}
+
+impl Shape for ExtendedShape {
+    fn score(&self) -> u64 {
+        match self {
+            ExtendedShape::Rock => 1,
+            ExtendedShape::Paper => 2,
+            ExtendedShape::Scissors => 3,
+            ExtendedShape::Lizard => 4,
+            ExtendedShape::Spock => 5,
+        }
+    }
+
+    fn defeats(&self, other: &Self) -> bool {
+        LIZARD_SPOCK_RULES.contains(&(*self, *other))
+    }
+}
+
+impl RPSAction {
+    pub fn score(&self) -> u64 {
+        match self {
+            RPSAction::Lose => 0,
+            RPSAction::Draw => 3,
+            RPSAction::Win => 6,
+        }
+    }
+}
+
+static OPPONENT_GAME_MAPPING: phf::Map<&'static str, RPSShape> = phf_map!(
+    "A" => RPSShape::Rock,
+    "B" => RPSShape::Paper,
+    "C" => RPSShape::Scissors,
+);
+
+static PART1_MY_GAME_MAPPING: phf::Map<&'static str, RPSShape> = phf_map!(
+    "X" => RPSShape::Rock,
+    "Y" => RPSShape::Paper,
+    "Z" => RPSShape::Scissors,
+);
+
+static PART2_MY_ACTIONS_MAPPING: phf::Map<&'static str, RPSAction> = phf_map!(
+    "X" => RPSAction::Lose,
+    "Y" => RPSAction::Draw,
+    "Z" => RPSAction::Win,
+);
+
+/// A single round, with the second column read both as my shape (part 1) and my action (part 2)
+pub struct Round {
+    pub opponent: RPSShape,
+    pub my_shape: RPSShape,
+    pub my_action: RPSAction,
+}
+
+static LIZARD_SPOCK_OPPONENT_MAPPING: phf::Map<&'static str, ExtendedShape> = phf_map!(
+    "A" => ExtendedShape::Rock,
+    "B" => ExtendedShape::Paper,
+    "C" => ExtendedShape::Scissors,
+    "D" => ExtendedShape::Lizard,
+    "E" => ExtendedShape::Spock,
+);
+
+static LIZARD_SPOCK_MY_MAPPING: phf::Map<&'static str, ExtendedShape> = phf_map!(
+    "X" => ExtendedShape::Rock,
+    "Y" => ExtendedShape::Paper,
+    "Z" => ExtendedShape::Scissors,
+    "V" => ExtendedShape::Lizard,
+    "W" => ExtendedShape::Spock,
+);
+
+/// Looks up the token in the column of the given line, naming both if it's missing or unknown
+fn lookup_token<V: Copy>(
+    mapping: &phf::Map<&'static str, V>,
+    token: Option<&str>,
+    line_number: usize,
+) -> Result<V, AocError> {
+    let token = token
+        .ok_or_else(|| AocError::Parse(format!("Line {}: expected two columns", line_number)))?;
+
+    mapping
+        .get(token)
+        .copied()
+        .ok_or_else(|| AocError::Parse(format!("Line {}: unknown token '{}'", line_number, token)))
+}
+
+pub fn parse_str(input: &str) -> Result<Vec<Round>, AocError> {
+    let mut rounds = Vec::new();
+
+    // Read line by line
+    for (i, line) in input.lines().enumerate() {
+        let mut fields_it = line.split_ascii_whitespace().take(2);
+        let opponent_game = fields_it.next();
+        let my_game = fields_it.next();
+
+        rounds.push(Round {
+            opponent: lookup_token(&OPPONENT_GAME_MAPPING, opponent_game, i + 1)?,
+            my_shape: lookup_token(&PART1_MY_GAME_MAPPING, my_game, i + 1)?,
+            my_action: lookup_token(&PART2_MY_ACTIONS_MAPPING, my_game, i + 1)?,
+        });
+    }
+
+    Ok(rounds)
+}
+
+pub fn parse_input<T: AsRef<Path>>(filename: T) -> Result<Vec<Round>, AocError> {
+    parse_str(&read_to_string(filename)?)
+}
+
+/// Parses rounds of the five-weapon variant, as (opponent, mine) shape pairs
+pub fn parse_str_lizard_spock(
+    input: &str,
+) -> Result<Vec<(ExtendedShape, ExtendedShape)>, AocError> {
+    let mut rounds = Vec::new();
+
+    for (i, line) in input.lines().enumerate() {
+        let mut fields_it = line.split_ascii_whitespace().take(2);
+        let opponent = lookup_token(&LIZARD_SPOCK_OPPONENT_MAPPING, fields_it.next(), i + 1)?;
+        let my_shape = lookup_token(&LIZARD_SPOCK_MY_MAPPING, fields_it.next(), i + 1)?;
+        rounds.push((opponent, my_shape));
+    }
+
+    Ok(rounds)
+}
+
+pub fn parse_input_lizard_spock<T: AsRef<Path>>(
+    filename: T,
+) -> Result<Vec<(ExtendedShape, ExtendedShape)>, AocError> {
+    parse_str_lizard_spock(&read_to_string(filename)?)
+}
+
+fn part1_round_score(round: &Round) -> u64 {
+    round_score(&round.my_shape, &round.opponent)
+}
+
+fn part2_round_score(round: &Round) -> u64 {
+    let my_shape = shape_for_outcome(&round.opponent, &round.my_action);
+    round_score(&my_shape, &round.opponent)
+}
+
+pub fn part1_calculate_scores(rounds: &[Round]) -> u64 {
+    rounds.iter().map(part1_round_score).sum()
+}
+
+pub fn part2_calculate_scores(rounds: &[Round]) -> u64 {
+    rounds.iter().map(part2_round_score).sum()
+}
+
+pub fn lizard_spock_calculate_scores(rounds: &[(ExtendedShape, ExtendedShape)]) -> u64 {
+    rounds
+        .iter()
+        .map(|(opponent, my_shape)| round_score(my_shape, opponent))
+        .sum()
+}
+
+/// Computes the part 1 and part 2 total scores in a single pass over the rounds
+pub fn calculate_scores(rounds: &[Round]) -> (u64, u64) {
+    rounds.iter().fold((0, 0), |(part1, part2), round| {
+        (
+            part1 + part1_round_score(round),
+            part2 + part2_round_score(round),
+        )
+    })
+}
+
+pub const INPUT_PATH: &str = "inputs/day02.in";
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+    const INPUT_PATH: &'static str = INPUT_PATH;
+    const PART1_LABEL: &'static str = "Total score";
+    const PART2_LABEL: &'static str = "Total score";
+
+    type Input = Vec<Round>;
+
+    fn parse(s: &str) -> Result<Self::Input, AocError> {
+        parse_str(s)
+    }
+
+    fn part1(rounds: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part1_calculate_scores(rounds).into())
+    }
+
+    fn part2(rounds: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part2_calculate_scores(rounds).into())
+    }
+}
+
+/// Solves both parts against the committed input
+pub fn run() -> Result<DayResult, AocError> {
+    solve::<Day02, _>(INPUT_PATH)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::solve_str;
+
+    const SAMPLE: &str = include_str!("../../../inputs/day02_test.in");
+
+    #[test]
+    fn part1_sample() {
+        let rounds = parse_str(SAMPLE).unwrap();
+        assert_eq!(part1_calculate_scores(&rounds), 15);
+    }
+
+    #[test]
+    fn part2_sample() {
+        let rounds = parse_str(SAMPLE).unwrap();
+        assert_eq!(part2_calculate_scores(&rounds), 12);
+    }
+
+    #[test]
+    fn shape_relations() {
+        for shape in [RPSShape::Rock, RPSShape::Paper, RPSShape::Scissors] {
+            assert_eq!(shape.beats().loses_to(), shape);
+            assert_eq!(shape.outcome_vs(&shape), RPSAction::Draw);
+            assert_eq!(shape.outcome_vs(&shape.beats()), RPSAction::Win);
+            assert_eq!(shape.outcome_vs(&shape.loses_to()), RPSAction::Lose);
+        }
+        assert_eq!(RPSShape::Paper.outcome_vs(&RPSShape::Rock), RPSAction::Win);
+    }
+
+    #[test]
+    fn shapes_for_outcomes() {
+        assert_eq!(
+            shape_for_outcome(&RPSShape::Paper, &RPSAction::Draw),
+            RPSShape::Paper
+        );
+        assert_eq!(
+            shape_for_outcome(&RPSShape::Paper, &RPSAction::Win),
+            RPSShape::Scissors
+        );
+        assert_eq!(
+            shape_for_outcome(&RPSShape::Paper, &RPSAction::Lose),
+            RPSShape::Rock
+        );
+
+        for opponent in [RPSShape::Rock, RPSShape::Paper, RPSShape::Scissors] {
+            for action in [RPSAction::Lose, RPSAction::Draw, RPSAction::Win] {
+                let shape = shape_for_outcome(&opponent, &action);
+                assert_eq!(shape.outcome_vs(&opponent), action);
+            }
+        }
+    }
+
+    #[test]
+    fn lizard_spock_scores() {
+        // The extended rules agree with the standard ones on the three original shapes
+        let rounds = parse_str_lizard_spock(SAMPLE).unwrap();
+        assert_eq!(lizard_spock_calculate_scores(&rounds), 15);
+
+        let rounds = parse_str_lizard_spock("A V\nB W\nC X\nD Y\nE Z").unwrap();
+        assert_eq!(lizard_spock_calculate_scores(&rounds), 4 + 5 + 7 + 2 + 3);
+    }
+
+    #[test]
+    fn lizard_spock_rules_are_consistent() {
+        let shapes = [
+            ExtendedShape::Rock,
+            ExtendedShape::Paper,
+            ExtendedShape::Scissors,
+            ExtendedShape::Lizard,
+            ExtendedShape::Spock,
+        ];
+        for shape in shapes {
+            let beaten = shapes.iter().filter(|other| shape.defeats(other)).count();
+            assert_eq!(beaten, 2);
+            for other in shapes {
+                assert!(!(shape.defeats(&other) && other.defeats(&shape)));
+            }
+        }
+    }
+
+    #[test]
+    fn unknown_token_error() {
+        let err = parse_str("A Y\nA Q").err().unwrap();
+        assert_eq!(err.to_string(), "Parse error: Line 2: unknown token 'Q'");
+
+        assert!(parse_str("A Q").is_err());
+        assert!(parse_str("A").is_err());
+        assert!(parse_str_lizard_spock("F X").is_err());
+    }
+
+    #[test]
+    fn single_pass_scores() {
+        let rounds = parse_str(SAMPLE).unwrap();
+        assert_eq!(calculate_scores(&rounds), (15, 12));
+    }
+
+    #[test]
+    fn empty_input() {
+        assert_eq!(
+            solve_str::<Day02>("").unwrap(),
+            ("0".to_string(), "0".to_string())
+        );
+    }
+}
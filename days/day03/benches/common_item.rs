@@ -0,0 +1,62 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day03::{find_common_item, find_group_badges, parse_input, INPUT_PATH};
+
+// The previous O(n²) implementations, kept as a baseline
+fn find_common_item_naive(items: &str) -> char {
+    let (first_container, second_container) = items.split_at(items.len() / 2);
+    for item in first_container.chars() {
+        if second_container.find(item).is_some() {
+            return item;
+        }
+    }
+
+    '\0'
+}
+
+fn find_group_badges_naive(rucksacks: &[String]) -> Vec<char> {
+    rucksacks
+        .chunks(3)
+        .map(|group| {
+            for item in group[0].chars() {
+                if group[1].find(item).is_some() && group[2].find(item).is_some() {
+                    return item;
+                }
+            }
+            panic!("Could not find the common item in the group of three!");
+        })
+        .collect()
+}
+
+fn bench_common_item(c: &mut Criterion) {
+    // Benches run from the crate directory
+    let rucksacks = parse_input(format!("../../{}", INPUT_PATH)).unwrap();
+
+    let mut group = c.benchmark_group("find_common_item");
+    group.bench_function("byte_set", |b| {
+        b.iter(|| {
+            for r in &rucksacks {
+                black_box(find_common_item(black_box(r)));
+            }
+        })
+    });
+    group.bench_function("naive", |b| {
+        b.iter(|| {
+            for r in &rucksacks {
+                black_box(find_common_item_naive(black_box(r)));
+            }
+        })
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("find_group_badges");
+    group.bench_function("byte_set", |b| {
+        b.iter(|| find_group_badges(black_box(&rucksacks), 3))
+    });
+    group.bench_function("naive", |b| {
+        b.iter(|| find_group_badges_naive(black_box(&rucksacks)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_common_item);
+criterion_main!(benches);
@@ -0,0 +1,390 @@
+use std::io::{self, BufRead};
+use std::path::Path;
+
+use aoc_common::{read_to_string, solve, Answer, AocError, DayResult, Solution};
+
+/// Membership set of the ASCII items in a container
+fn item_set(items: &str) -> [bool; 128] {
+    let mut set = [false; 128];
+    for item in items.bytes().filter(u8::is_ascii) {
+        set[item as usize] = true;
+    }
+
+    set
+}
+
+/// Every distinct item present in both compartments, in the order they appear in the first one.
+/// Items are ASCII letters, so a rucksack with anything else can't be split and shares none
+pub fn shared_items(items: &str) -> Vec<char> {
+    if !items.is_ascii() {
+        return Vec::new();
+    }
+
+    let (first_container, second_container) = items.split_at(items.len() / 2);
+    let mut second_set = item_set(second_container);
+
+    // Unmark each item once it's taken, so it's only reported once
+    let mut shared = Vec::new();
+    for item in first_container.bytes().filter(u8::is_ascii) {
+        if second_set[item as usize] {
+            second_set[item as usize] = false;
+            shared.push(char::from(item));
+        }
+    }
+
+    shared
+}
+
+pub fn find_common_item(items: &str) -> Option<char> {
+    shared_items(items).first().copied()
+}
+
+pub fn find_group_badges(rucksacks: &[String], group_size: usize) -> Result<Vec<char>, AocError> {
+    if group_size == 0 || !rucksacks.len().is_multiple_of(group_size) {
+        return Err(AocError::Parse(format!(
+            "{} rucksacks can't be split into groups of {}",
+            rucksacks.len(),
+            group_size
+        )));
+    }
+
+    // Iterate over the groups and map each one to the item common to all its rucksacks
+    rucksacks.chunks(group_size).map(group_badge).collect()
+}
+
+/// Item common to every rucksack of a group, which must not be empty
+fn group_badge(group: &[String]) -> Result<char, AocError> {
+    // Intersect the item sets of every rucksack but the first
+    let mut common_set = [true; 128];
+    for rucksack in &group[1..] {
+        let rucksack_set = item_set(rucksack);
+        for (common, &present) in common_set.iter_mut().zip(&rucksack_set) {
+            *common &= present;
+        }
+    }
+
+    group[0]
+        .bytes()
+        .find(|&item| item.is_ascii() && common_set[item as usize])
+        .map(char::from)
+        .ok_or_else(|| {
+            AocError::Parse(format!(
+                "Could not find the common item in the group starting with '{}'",
+                group[0]
+            ))
+        })
+}
+
+fn get_priority(item: char) -> Result<u64, AocError> {
+    // Use ASCII table to easily calculate the priority of an item
+    if item.is_ascii_lowercase() {
+        Ok(item as u64 - 96)
+    } else if item.is_ascii_uppercase() {
+        Ok(item as u64 - 38)
+    } else {
+        Err(AocError::Parse(format!(
+            "Item '{}' is not an ASCII letter",
+            item
+        )))
+    }
+}
+
+pub fn parse_str(input: &str) -> Vec<String> {
+    // Trim so a stray '\r' never ends up as an item
+    input.lines().map(|line| line.trim().to_string()).collect()
+}
+
+pub fn parse_input<T: AsRef<Path>>(filename: T) -> io::Result<Vec<String>> {
+    Ok(parse_str(&read_to_string(filename)?))
+}
+
+/// Priority of the item shared by both compartments of a rucksack
+fn common_item_priority(rucksack: &str) -> Result<u64, AocError> {
+    if !rucksack.is_ascii() {
+        return Err(AocError::Parse(format!(
+            "Rucksack '{}' holds non-ASCII items",
+            rucksack
+        )));
+    }
+
+    let common_item = find_common_item(rucksack).ok_or_else(|| {
+        AocError::Parse(format!(
+            "No item shared by both compartments of '{}'",
+            rucksack
+        ))
+    })?;
+    get_priority(common_item)
+}
+
+pub fn part1(rucksacks: &[String]) -> Result<u64, AocError> {
+    rucksacks.iter().map(|r| common_item_priority(r)).sum()
+}
+
+pub fn part2(rucksacks: &[String]) -> Result<u64, AocError> {
+    let badges = find_group_badges(rucksacks, 3)?;
+    badges.iter().map(|&b| get_priority(b)).sum()
+}
+
+/// Reads the next line into `buf` without its surrounding whitespace, reusing its allocation.
+/// Returns false once the input is over
+fn read_trimmed_line<R: BufRead>(r: &mut R, buf: &mut String) -> io::Result<bool> {
+    buf.clear();
+    if r.read_line(buf)? == 0 {
+        return Ok(false);
+    }
+
+    buf.truncate(buf.trim_end().len());
+    let leading_whitespace = buf.len() - buf.trim_start().len();
+    buf.drain(..leading_whitespace);
+
+    Ok(true)
+}
+
+fn invalid_input(e: AocError) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, e)
+}
+
+/// Same as `part1`, handling each rucksack as it's read instead of collecting them first
+pub fn part1_streaming<R: BufRead>(mut r: R) -> io::Result<u64> {
+    let mut rucksack = String::new();
+    let mut sum = 0;
+    while read_trimmed_line(&mut r, &mut rucksack)? {
+        sum += common_item_priority(&rucksack).map_err(invalid_input)?;
+    }
+
+    Ok(sum)
+}
+
+/// Same as `part2`, only keeping the rucksacks of the current group in memory
+pub fn part2_streaming<R: BufRead>(mut r: R) -> io::Result<u64> {
+    let mut group: [String; 3] = Default::default();
+    let mut rucksacks_count = 0;
+    let mut sum = 0;
+    while read_trimmed_line(&mut r, &mut group[rucksacks_count % 3])? {
+        rucksacks_count += 1;
+
+        // Every third rucksack closes a group
+        if rucksacks_count.is_multiple_of(3) {
+            let badge = group_badge(&group).map_err(invalid_input)?;
+            sum += get_priority(badge).map_err(invalid_input)?;
+        }
+    }
+
+    if !rucksacks_count.is_multiple_of(3) {
+        return Err(invalid_input(AocError::Parse(format!(
+            "{} rucksacks can't be split into groups of 3",
+            rucksacks_count
+        ))));
+    }
+
+    Ok(sum)
+}
+
+/// Computes the part 1 and part 2 priority sums in a single pass over the rucksacks
+pub fn calculate_priorities(rucksacks: &[String]) -> Result<(u64, u64), AocError> {
+    if !rucksacks.len().is_multiple_of(3) {
+        return Err(AocError::Parse(format!(
+            "{} rucksacks can't be split into groups of 3",
+            rucksacks.len()
+        )));
+    }
+
+    let mut compartments_sum = 0;
+    let mut badges_sum = 0;
+    for group in rucksacks.chunks(3) {
+        for r in group {
+            compartments_sum += common_item_priority(r)?;
+        }
+
+        // Same badge as `find_group_badges`, even when the group shares several items
+        badges_sum += get_priority(group_badge(group)?)?;
+    }
+
+    Ok((compartments_sum, badges_sum))
+}
+
+pub const INPUT_PATH: &str = "inputs/day03.in";
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+    const INPUT_PATH: &'static str = INPUT_PATH;
+    const PART1_LABEL: &'static str = "Priorities sum";
+    const PART2_LABEL: &'static str = "Badge priorities sum";
+
+    type Input = Vec<String>;
+
+    fn parse(s: &str) -> Result<Self::Input, AocError> {
+        Ok(parse_str(s))
+    }
+
+    fn part1(rucksacks: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part1(rucksacks)?.into())
+    }
+
+    fn part2(rucksacks: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part2(rucksacks)?.into())
+    }
+}
+
+/// Solves both parts against the committed input
+pub fn run() -> Result<DayResult, AocError> {
+    solve::<Day03, _>(INPUT_PATH)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::solve_str;
+
+    const SAMPLE: &str = include_str!("../../../inputs/day03_test.in");
+
+    #[test]
+    fn part1_sample() {
+        assert_eq!(part1(&parse_str(SAMPLE)).unwrap(), 157);
+    }
+
+    #[test]
+    fn part2_sample() {
+        assert_eq!(part2(&parse_str(SAMPLE)).unwrap(), 70);
+    }
+
+    #[test]
+    fn single_pass_priorities() {
+        assert_eq!(calculate_priorities(&parse_str(SAMPLE)).unwrap(), (157, 70));
+        assert!(calculate_priorities(&parse_str("abcabc")).is_err());
+    }
+
+    #[test]
+    fn other_group_sizes() {
+        let rucksacks = parse_str(SAMPLE);
+        assert_eq!(
+            find_group_badges(&rucksacks, 2).unwrap(),
+            vec!['r', 'q', 'J']
+        );
+        assert!(find_group_badges(&rucksacks, 4).is_err());
+        // All six rucksacks don't share any item
+        assert!(find_group_badges(&rucksacks, 6).is_err());
+        assert!(find_group_badges(&rucksacks, 0).is_err());
+    }
+
+    #[test]
+    fn all_shared_items() {
+        assert_eq!(shared_items("vJrwpWtwJgWrhcsFMMfFFhFp"), vec!['p']);
+        assert_eq!(shared_items("cbcaXabYbc"), vec!['c', 'b', 'a']);
+        assert!(shared_items("abcABC").is_empty());
+        assert_eq!(find_common_item("cbcaXabYbc"), Some('c'));
+    }
+
+    #[test]
+    fn group_sharing_two_items() {
+        // Every rucksack holds both 'b' and 'a', the first one seeing 'b' first
+        let rucksacks = parse_str("bxax\naybcyc\nazbz");
+        assert_eq!(find_group_badges(&rucksacks, 3).unwrap(), vec!['b']);
+        assert_eq!(part2(&rucksacks).unwrap(), 2);
+        assert_eq!(calculate_priorities(&rucksacks).unwrap().1, 2);
+    }
+
+    #[test]
+    fn malformed_rucksacks() {
+        assert_eq!(find_common_item("abcABC"), None);
+        assert!(part1(&parse_str("abcABC")).is_err());
+        assert!(part1(&parse_str("a1b1")).is_err());
+        assert!(get_priority('é').is_err());
+
+        // Splitting in the middle of 'é' used to panic
+        assert!(shared_items("aébc").is_empty());
+        assert_eq!(
+            part1(&parse_str("aébc")).err().unwrap().to_string(),
+            "Parse error: Rucksack 'aébc' holds non-ASCII items"
+        );
+        assert!(calculate_priorities(&parse_str("aébc\nab\nab")).is_err());
+        assert!(part1_streaming("aébc\n".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn streaming_matches_batch() {
+        let crlf_sample = SAMPLE.replace('\n', "\r\n");
+        for input in [SAMPLE, &crlf_sample, " vJrwpWtwJgWrhcsFMMfFFhFp \n"] {
+            let rucksacks = parse_str(input);
+            assert_eq!(
+                part1_streaming(input.as_bytes()).unwrap(),
+                part1(&rucksacks).unwrap()
+            );
+        }
+        for input in [SAMPLE, &crlf_sample] {
+            assert_eq!(
+                part2_streaming(input.as_bytes()).unwrap(),
+                part2(&parse_str(input)).unwrap()
+            );
+        }
+
+        assert!(part1_streaming("abcABC\n".as_bytes()).is_err());
+        let two_groups_and_one = format!("{}\nvJrwpWtwJgWrhcsFMMfFFhFp\n", SAMPLE.trim_end());
+        assert!(part2_streaming(two_groups_and_one.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn crlf_input() {
+        let crlf_sample = SAMPLE.replace('\n', "\r\n");
+        assert_eq!(part1(&parse_str(&crlf_sample)).unwrap(), 157);
+        assert_eq!(part2(&parse_str(&crlf_sample)).unwrap(), 70);
+    }
+
+    #[test]
+    fn empty_input() {
+        assert_eq!(
+            solve_str::<Day03>("").unwrap(),
+            ("0".to_string(), "0".to_string())
+        );
+    }
+}
+
+#[cfg(test)]
+mod proptests {
+    use super::*;
+
+    use proptest::prelude::*;
+
+    fn letters() -> Vec<char> {
+        ('a'..='z').chain('A'..='Z').collect()
+    }
+
+    /// A rucksack whose compartments share exactly one item, along with that item
+    fn rucksack_with_common_item() -> impl Strategy<Value = (String, char)> {
+        (Just(letters()).prop_shuffle(), 0..=25usize)
+            .prop_flat_map(|(letters, n)| (Just(letters), Just(n), 0..=n, 0..=n))
+            .prop_map(|(letters, n, first_position, second_position)| {
+                // The first letter is the shared item, the others are split between compartments
+                let common_item = letters[0];
+                let mut first: Vec<char> = letters[1..=n].to_vec();
+                let mut second: Vec<char> = letters[n + 1..=2 * n].to_vec();
+                first.insert(first_position, common_item);
+                second.insert(second_position, common_item);
+
+                (first.into_iter().chain(second).collect(), common_item)
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn priority_in_range(item in prop::sample::select(letters())) {
+            prop_assert!((1..=52).contains(&get_priority(item).unwrap()));
+        }
+
+        #[test]
+        fn priority_monotonic_within_case(a in 0..26u8, b in 0..26u8) {
+            prop_assume!(a < b);
+            for base in [b'a', b'A'] {
+                let (lower, higher) = (char::from(base + a), char::from(base + b));
+                prop_assert!(get_priority(lower).unwrap() < get_priority(higher).unwrap());
+            }
+        }
+
+        #[test]
+        fn common_item_recovered((rucksack, common_item) in rucksack_with_common_item()) {
+            prop_assert_eq!(find_common_item(&rucksack), Some(common_item));
+        }
+    }
+}
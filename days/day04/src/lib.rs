@@ -0,0 +1,332 @@
+use std::ops::RangeInclusive;
+use std::path::Path;
+
+use aoc_common::{read_to_string, solve, Answer, AocError, DayResult, Solution};
+
+/// Inclusive range of section IDs assigned to an elf, whose start is never past its end
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SectionRange(RangeInclusive<u64>);
+
+impl SectionRange {
+    /// Range between the two bounds, swapping them if they're reversed
+    pub fn new(start: u64, end: u64) -> Self {
+        SectionRange(start.min(end)..=start.max(end))
+    }
+
+    pub fn start(&self) -> u64 {
+        *self.0.start()
+    }
+
+    pub fn end(&self) -> u64 {
+        *self.0.end()
+    }
+
+    pub fn contains_range(&self, other: &SectionRange) -> bool {
+        self.0.contains(other.0.start()) && self.0.contains(other.0.end())
+    }
+
+    pub fn overlaps(&self, other: &SectionRange) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Number of sections in the range, or `None` if it spans every `u64` and the count doesn't
+    /// fit one
+    pub fn size(&self) -> Option<u64> {
+        (self.end() - self.start()).checked_add(1)
+    }
+
+    /// The sections shared by both ranges, if there are any
+    pub fn intersection(&self, other: &SectionRange) -> Option<SectionRange> {
+        let start = *self.0.start().max(other.0.start());
+        let end = *self.0.end().min(other.0.end());
+        (start <= end).then(|| SectionRange::new(start, end))
+    }
+}
+
+pub type RangePair = (SectionRange, SectionRange);
+
+/// Splits `s` around its only `separator`, failing if there's none or more than one
+fn split_pair<'a>(
+    s: &'a str,
+    separator: char,
+    missing_name: &str,
+    line_number: usize,
+) -> Result<(&'a str, &'a str), AocError> {
+    let (first, second) = s.split_once(separator).ok_or_else(|| {
+        AocError::Parse(format!("Line {}: missing {}", line_number, missing_name))
+    })?;
+    if second.contains(separator) {
+        return Err(AocError::Parse(format!(
+            "Line {}: unexpected '{}' after the {} in '{}'",
+            line_number, separator, missing_name, s
+        )));
+    }
+
+    Ok((first, second))
+}
+
+/// Parses a "start-end" range, swapping the bounds if they're reversed
+fn parse_range(
+    range_str: &str,
+    range_name: &str,
+    line_number: usize,
+) -> Result<SectionRange, AocError> {
+    let (start_str, end_str) =
+        split_pair(range_str, '-', &format!("{} end", range_name), line_number)?;
+    let parse_bound = |bound_str: &str, bound_name: &str| -> Result<u64, AocError> {
+        bound_str.trim().parse().map_err(|e| {
+            AocError::Parse(format!(
+                "Line {}: invalid {} {} '{}' ({})",
+                line_number, range_name, bound_name, bound_str, e
+            ))
+        })
+    };
+
+    Ok(SectionRange::new(
+        parse_bound(start_str, "start")?,
+        parse_bound(end_str, "end")?,
+    ))
+}
+
+pub fn parse_str(input: &str) -> Result<Vec<RangePair>, AocError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, rp)| {
+            // Split line to get range pairs
+            let (range1_str, range2_str) = split_pair(rp, ',', "second range", i + 1)?;
+            let range1 = parse_range(range1_str, "first range", i + 1)?;
+            let range2 = parse_range(range2_str, "second range", i + 1)?;
+
+            Ok((range1, range2))
+        })
+        .collect()
+}
+
+pub fn parse_input<T: AsRef<Path>>(filename: T) -> Result<Vec<RangePair>, AocError> {
+    parse_str(&read_to_string(filename)?)
+}
+
+fn ranges_fully_overlap((range1, range2): &RangePair) -> bool {
+    range1.contains_range(range2) || range2.contains_range(range1)
+}
+
+fn ranges_partially_overlap((range1, range2): &RangePair) -> bool {
+    range1.overlaps(range2)
+}
+
+pub fn part1(range_pairs: &[RangePair]) -> usize {
+    range_pairs
+        .iter()
+        .filter(|&p| ranges_fully_overlap(p))
+        .count()
+}
+
+pub fn part2(range_pairs: &[RangePair]) -> usize {
+    range_pairs
+        .iter()
+        .filter(|&p| ranges_partially_overlap(p))
+        .count()
+}
+
+/// Counts the fully and the partially overlapping pairs in a single pass
+pub fn count_overlaps(range_pairs: &[RangePair]) -> (usize, usize) {
+    let mut full_count = 0;
+    let mut partial_count = 0;
+    for range_pair in range_pairs {
+        // A full overlap is also a partial one, so only check the latter if needed
+        if ranges_fully_overlap(range_pair) {
+            full_count += 1;
+            partial_count += 1;
+        } else if ranges_partially_overlap(range_pair) {
+            partial_count += 1;
+        }
+    }
+
+    (full_count, partial_count)
+}
+
+/// Sums the number of sections shared within each pair, failing if the sum doesn't fit a `u64`
+pub fn total_overlapping_units(range_pairs: &[RangePair]) -> Result<u64, AocError> {
+    range_pairs
+        .iter()
+        .filter_map(|(range1, range2)| range1.intersection(range2))
+        .try_fold(0u64, |sum, shared| {
+            shared
+                .size()
+                .and_then(|size| sum.checked_add(size))
+                .ok_or_else(|| AocError::Overflow("Total of shared sections overflows".to_string()))
+        })
+}
+
+pub const INPUT_PATH: &str = "inputs/day04.in";
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+    const INPUT_PATH: &'static str = INPUT_PATH;
+    const PART1_LABEL: &'static str = "Fully overlapping pairs";
+    const PART2_LABEL: &'static str = "Overlapping pairs";
+
+    type Input = Vec<RangePair>;
+
+    fn parse(s: &str) -> Result<Self::Input, AocError> {
+        parse_str(s)
+    }
+
+    fn part1(range_pairs: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part1(range_pairs).into())
+    }
+
+    fn part2(range_pairs: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part2(range_pairs).into())
+    }
+}
+
+/// Solves both parts against the committed input
+pub fn run() -> Result<DayResult, AocError> {
+    solve::<Day04, _>(INPUT_PATH)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::solve_str;
+
+    const SAMPLE: &str = include_str!("../../../inputs/day04_test.in");
+
+    #[test]
+    fn part1_sample() {
+        let range_pairs = parse_str(SAMPLE).unwrap();
+        assert_eq!(part1(&range_pairs), 2);
+    }
+
+    #[test]
+    fn part2_sample() {
+        let range_pairs = parse_str(SAMPLE).unwrap();
+        assert_eq!(part2(&range_pairs), 4);
+    }
+
+    #[test]
+    fn section_range_relations() {
+        let range = SectionRange::new(2, 8);
+        assert!(range.contains_range(&SectionRange::new(3, 7)));
+        assert!(!range.contains_range(&SectionRange::new(3, 9)));
+        assert!(range.overlaps(&SectionRange::new(8, 9)));
+        assert!(!range.overlaps(&SectionRange::new(9, 9)));
+
+        assert_eq!(
+            range.intersection(&SectionRange::new(6, 12)),
+            Some(SectionRange::new(6, 8))
+        );
+        assert_eq!(range.intersection(&SectionRange::new(0, 1)), None);
+    }
+
+    #[test]
+    fn overlapping_units() {
+        let range_pairs = parse_str(SAMPLE).unwrap();
+        assert_eq!(
+            total_overlapping_units(&range_pairs).unwrap(),
+            1 + 5 + 1 + 3
+        );
+
+        let whole = SectionRange::new(0, u64::MAX);
+        assert_eq!(whole.size(), None);
+        assert_eq!(SectionRange::new(1, u64::MAX).size(), Some(u64::MAX));
+        assert!(matches!(
+            total_overlapping_units(&[(whole.clone(), whole)]),
+            Err(AocError::Overflow(_))
+        ));
+        let half = SectionRange::new(1, u64::MAX / 2 + 1);
+        assert!(matches!(
+            total_overlapping_units(&[(half.clone(), half.clone()), (half.clone(), half)]),
+            Err(AocError::Overflow(_))
+        ));
+    }
+
+    #[test]
+    fn reversed_ranges() {
+        let range_pairs = parse_str("8-2,3-5").unwrap();
+        assert_eq!(
+            range_pairs,
+            vec![(SectionRange::new(2, 8), SectionRange::new(3, 5))]
+        );
+        assert_eq!(count_overlaps(&range_pairs), (1, 1));
+    }
+
+    #[test]
+    fn malformed_lines() {
+        let error_message = |input| parse_str(input).err().unwrap().to_string();
+        assert_eq!(
+            error_message("2-4,6-8\n2-4,x-8"),
+            "Parse error: Line 2: invalid second range start 'x' (invalid digit found in string)"
+        );
+        assert_eq!(
+            error_message("2-4"),
+            "Parse error: Line 1: missing second range"
+        );
+        assert_eq!(
+            error_message("2-4,6"),
+            "Parse error: Line 1: missing second range end"
+        );
+        assert_eq!(
+            error_message("1-2-3,4-5"),
+            "Parse error: Line 1: unexpected '-' after the first range end in '1-2-3'"
+        );
+        assert_eq!(
+            error_message("1-2,3-4,5-6"),
+            "Parse error: Line 1: unexpected ',' after the second range in '1-2,3-4,5-6'"
+        );
+    }
+
+    #[test]
+    fn single_pass_overlaps() {
+        let range_pairs = parse_str(SAMPLE).unwrap();
+        assert_eq!(count_overlaps(&range_pairs), (2, 4));
+    }
+
+    #[test]
+    fn empty_input() {
+        assert_eq!(
+            solve_str::<Day04>("").unwrap(),
+            ("0".to_string(), "0".to_string())
+        );
+    }
+}
+
+#[cfg(test)]
+mod proptests {
+    use super::*;
+
+    use proptest::prelude::*;
+
+    /// Ranges over a small span of sections, so that pairs often touch or overlap
+    fn section_range() -> impl Strategy<Value = SectionRange> {
+        (0..100u64, 0..100u64).prop_map(|(a, b)| SectionRange::new(a, b))
+    }
+
+    fn range_pair() -> impl Strategy<Value = RangePair> {
+        (section_range(), section_range())
+    }
+
+    proptest! {
+        #[test]
+        fn full_overlap_implies_partial(pair in range_pair()) {
+            prop_assert!(!ranges_fully_overlap(&pair) || ranges_partially_overlap(&pair));
+        }
+
+        #[test]
+        fn overlap_is_symmetric((range1, range2) in range_pair()) {
+            let swapped = (range2.clone(), range1.clone());
+            let pair = (range1, range2);
+            prop_assert_eq!(ranges_fully_overlap(&pair), ranges_fully_overlap(&swapped));
+            prop_assert_eq!(ranges_partially_overlap(&pair), ranges_partially_overlap(&swapped));
+        }
+
+        #[test]
+        fn range_fully_overlaps_itself(range in section_range()) {
+            prop_assert!(ranges_fully_overlap(&(range.clone(), range)));
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day05::{parse_str, INPUT_PATH};
+use regex::Regex;
+
+/// How many times each benchmark iteration parses the input
+const PARSES: usize = 100;
+
+/// Compiles the regexes the way the previous parser did on every call
+fn compile_regexes() -> (Regex, Regex) {
+    (
+        Regex::new(r"(\s{3}|(?:\[(\w)\]))\s?").unwrap(),
+        Regex::new(r"move\s+(\d+)\s+from\s+(\d+)\s+to\s+(\d+)").unwrap(),
+    )
+}
+
+fn bench_parse(c: &mut Criterion) {
+    // Benches run from the crate directory
+    let input = std::fs::read_to_string(format!("../../{}", INPUT_PATH)).unwrap();
+
+    let mut group = c.benchmark_group("parse_100_times");
+    group.bench_function("cached_regexes", |b| {
+        b.iter(|| {
+            for _ in 0..PARSES {
+                black_box(parse_str(black_box(&input)).unwrap());
+            }
+        })
+    });
+    group.bench_function("compiled_per_parse", |b| {
+        b.iter(|| {
+            for _ in 0..PARSES {
+                black_box(compile_regexes());
+                black_box(parse_str(black_box(&input)).unwrap());
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_parse);
+criterion_main!(benches);
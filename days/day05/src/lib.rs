@@ -0,0 +1,490 @@
+use std::fmt::Display;
+use std::io;
+use std::path::Path;
+
+use aoc_common::{read_to_string, solve, Answer, AocError, DayResult, Solution};
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// A crate like `[A]` or the empty slot of a stack, along with the space after it
+static STACK_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(\s{3}|(?:\[(\w)\]))\s?").expect("Error compiling regex"));
+static MOVE_INSTRUCTION_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"move\s+(\d+)\s+from\s+(\d+)\s+to\s+(\d+)").expect("Error compiling regex")
+});
+
+#[derive(Debug, Clone)]
+pub struct MoveInstruction {
+    amount: usize,
+    origin: usize,
+    destination: usize,
+}
+
+impl MoveInstruction {
+    fn new(amount: usize, origin: usize, destination: usize) -> Self {
+        MoveInstruction {
+            amount,
+            origin,
+            destination,
+        }
+    }
+}
+
+/// A crane behavior, applying an already validated instruction to the stacks
+pub trait Mover {
+    fn apply(&self, stacks: &mut [Vec<char>], inst: &MoveInstruction);
+
+    /// Reverts an instruction that was the last one applied
+    fn undo(&self, stacks: &mut [Vec<char>], inst: &MoveInstruction);
+}
+
+impl MoveInstruction {
+    /// The same move, going from the destination back to the origin
+    fn inverse(&self) -> Self {
+        MoveInstruction::new(self.amount, self.destination, self.origin)
+    }
+}
+
+/// Moves crates one at a time, so they land in reverse order
+pub struct CM9000;
+
+/// Moves several crates at once, keeping their order
+pub struct CM9001;
+
+impl Mover for CM9000 {
+    fn apply(&self, stacks: &mut [Vec<char>], inst: &MoveInstruction) {
+        for _ in 0..inst.amount {
+            if let Some(moving_crate) = stacks[inst.origin - 1].pop() {
+                stacks[inst.destination - 1].push(moving_crate);
+            }
+        }
+    }
+
+    fn undo(&self, stacks: &mut [Vec<char>], inst: &MoveInstruction) {
+        // Popping the crates back one at a time reverses them again
+        self.apply(stacks, &inst.inverse());
+    }
+}
+
+impl Mover for CM9001 {
+    fn apply(&self, stacks: &mut [Vec<char>], inst: &MoveInstruction) {
+        let split_index = stacks[inst.origin - 1].len().saturating_sub(inst.amount);
+        let moving_crates = stacks[inst.origin - 1].split_off(split_index);
+        stacks[inst.destination - 1].extend(moving_crates);
+    }
+
+    fn undo(&self, stacks: &mut [Vec<char>], inst: &MoveInstruction) {
+        self.apply(stacks, &inst.inverse());
+    }
+}
+
+pub struct Cargo {
+    stacks: Vec<Vec<char>>,
+    mover: Box<dyn Mover>,
+    history: Vec<MoveInstruction>,
+}
+
+impl Display for Cargo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut max_height = self
+            .stacks
+            .iter()
+            .map(|s| s.len())
+            .max()
+            .expect("No stacks to display");
+        while max_height > 0 {
+            for (i, stack) in self.stacks.iter().enumerate() {
+                let suffix = if i == self.stacks.len() - 1 { "" } else { " " };
+                let crate_rep = if let Some(name) = stack.get(max_height - 1) {
+                    format!("[{}]", name)
+                } else {
+                    "   ".to_string()
+                };
+                write!(f, "{}{}", crate_rep, suffix)?;
+            }
+            writeln!(f)?;
+
+            max_height -= 1;
+        }
+
+        // Center each number under its 3 character wide column
+        let crate_numbers = (1..=self.stacks.len())
+            .map(|n| format!("{:^3}", n))
+            .collect::<Vec<_>>()
+            .join(" ");
+        write!(f, "{}", crate_numbers.trim_end())
+    }
+}
+
+impl Cargo {
+    pub fn new(stacks: Vec<Vec<char>>, mover: Box<dyn Mover>) -> Self {
+        Cargo {
+            stacks,
+            mover,
+            history: Vec::new(),
+        }
+    }
+
+    pub fn move_cargo(&mut self, instruction: &MoveInstruction) -> Result<(), AocError> {
+        // Check the instruction against the current stacks before moving anything
+        let stacks_amount = self.stacks.len();
+        for stack_number in [instruction.origin, instruction.destination] {
+            if stack_number == 0 || stack_number > stacks_amount {
+                return Err(AocError::Parse(format!(
+                    "Stack {} doesn't exist in {:?}, there are {} stacks",
+                    stack_number, instruction, stacks_amount
+                )));
+            }
+        }
+        let origin_height = self.stacks[instruction.origin - 1].len();
+        if origin_height < instruction.amount {
+            return Err(AocError::Parse(format!(
+                "Stack {} only has {} crates for {:?}",
+                instruction.origin, origin_height, instruction
+            )));
+        }
+
+        self.mover.apply(&mut self.stacks, instruction);
+        self.history.push(instruction.clone());
+
+        Ok(())
+    }
+
+    /// Reverts the last applied move, returning it, or `None` if there's nothing to undo
+    pub fn undo(&mut self) -> Option<MoveInstruction> {
+        let instruction = self.history.pop()?;
+        self.mover.undo(&mut self.stacks, &instruction);
+
+        Some(instruction)
+    }
+
+    /// Number of moves that have been applied and not undone
+    pub fn history_len(&self) -> usize {
+        self.history.len()
+    }
+
+    pub fn get_top_string(&self) -> String {
+        self.stacks
+            .iter()
+            .map(|s| s.last().map_or("".to_string(), |c| c.to_string()))
+            .collect::<Vec<_>>()
+            .join("")
+    }
+}
+
+pub fn parse_str(input: &str) -> io::Result<(Vec<Vec<char>>, Vec<MoveInstruction>)> {
+    let mut lines_it = input.lines().enumerate();
+
+    // Collect the crate rows, up to the line numbering the stacks
+    let mut crate_lines = Vec::new();
+    let stacks_amount = loop {
+        let (_, line) = lines_it.next().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, "Expected input not found")
+        })?;
+
+        // Done reading the stacks once every token is a stack number, which may have several digits
+        let mut labels = line.split_ascii_whitespace().peekable();
+        if labels.peek().is_some() && labels.clone().all(|l| l.parse::<usize>().is_ok()) {
+            break labels.count();
+        }
+
+        crate_lines.push(line);
+    };
+
+    // Push crates to their stacks, from the bottom row up
+    let mut stacks = vec![Vec::new(); stacks_amount];
+    for line in crate_lines.into_iter().rev() {
+        for (i, cap) in STACK_RE.captures_iter(line).enumerate() {
+            if let Some(crate_match) = cap.get(2) {
+                let crate_name = crate_match
+                    .as_str()
+                    .chars()
+                    .next()
+                    .expect("Match string is empty");
+                let stack = stacks.get_mut(i).ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("Crate '{}' is beyond the last stack", crate_name),
+                    )
+                })?;
+                stack.push(crate_name);
+            }
+        }
+    }
+
+    // Parse move instructions, pointing at the 1-based line of the first one that isn't valid.
+    // Blank lines are skipped, including the one that usually follows the stack numbers
+    let mut instructions = Vec::new();
+    for (i, line) in lines_it {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let invalid_move = |reason: &str| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Line {}: {} in '{}'", i + 1, reason, line),
+            )
+        };
+        let number_cap = MOVE_INSTRUCTION_RE
+            .captures(line)
+            .ok_or_else(|| invalid_move("expected 'move N from N to N'"))?;
+        let number = |group: usize| {
+            number_cap[group]
+                .parse::<usize>()
+                .map_err(|e| invalid_move(&e.to_string()))
+        };
+        instructions.push(MoveInstruction::new(number(1)?, number(2)?, number(3)?));
+    }
+
+    Ok((stacks, instructions))
+}
+
+pub fn parse_input<T: AsRef<Path>>(
+    filename: T,
+) -> io::Result<(Vec<Vec<char>>, Vec<MoveInstruction>)> {
+    parse_str(&read_to_string(filename)?)
+}
+
+fn operate_crane(
+    stacks: Vec<Vec<char>>,
+    instructions: &[MoveInstruction],
+    mover: Box<dyn Mover>,
+) -> Result<Cargo, AocError> {
+    let mut cargo = Cargo::new(stacks, mover);
+    for inst in instructions {
+        cargo.move_cargo(inst)?;
+    }
+
+    Ok(cargo)
+}
+
+pub fn part1(stacks: Vec<Vec<char>>, instructions: &[MoveInstruction]) -> Result<Cargo, AocError> {
+    operate_crane(stacks, instructions, Box::new(CM9000))
+}
+
+pub fn part2(stacks: Vec<Vec<char>>, instructions: &[MoveInstruction]) -> Result<Cargo, AocError> {
+    operate_crane(stacks, instructions, Box::new(CM9001))
+}
+
+pub const INPUT_PATH: &str = "inputs/day05.in";
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+    const INPUT_PATH: &'static str = INPUT_PATH;
+    const PART1_LABEL: &'static str = "Top string with the CrateMover 9000";
+    const PART2_LABEL: &'static str = "Top string with the CrateMover 9001";
+
+    type Input = (Vec<Vec<char>>, Vec<MoveInstruction>);
+
+    fn parse(s: &str) -> Result<Self::Input, AocError> {
+        Ok(parse_str(s)?)
+    }
+
+    fn part1((stacks, instructions): &Self::Input) -> Result<Answer, AocError> {
+        Ok(part1(stacks.clone(), instructions)?.get_top_string().into())
+    }
+
+    fn part2((stacks, instructions): &Self::Input) -> Result<Answer, AocError> {
+        Ok(part2(stacks.clone(), instructions)?.get_top_string().into())
+    }
+
+    fn part1_details((stacks, instructions): &Self::Input) -> Result<Option<String>, AocError> {
+        let cargo = part1(stacks.clone(), instructions)?;
+        Ok(Some(format!("Final distribution:\n\n{}", cargo)))
+    }
+
+    fn part2_details((stacks, instructions): &Self::Input) -> Result<Option<String>, AocError> {
+        let cargo = part2(stacks.clone(), instructions)?;
+        Ok(Some(format!("Final distribution:\n\n{}", cargo)))
+    }
+}
+
+/// Solves both parts against the committed input
+pub fn run() -> Result<DayResult, AocError> {
+    solve::<Day05, _>(INPUT_PATH)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("../../../inputs/day05_test.in");
+
+    #[test]
+    fn part1_sample() {
+        let (stacks, instructions) = parse_str(SAMPLE).unwrap();
+        assert_eq!(
+            part1(stacks, &instructions).unwrap().get_top_string(),
+            "CMZ"
+        );
+    }
+
+    #[test]
+    fn part2_sample() {
+        let (stacks, instructions) = parse_str(SAMPLE).unwrap();
+        assert_eq!(
+            part2(stacks, &instructions).unwrap().get_top_string(),
+            "MCD"
+        );
+    }
+
+    /// Experimental crane that reverses the whole origin stack before moving the top crates
+    struct ReverseAndMove;
+
+    impl Mover for ReverseAndMove {
+        fn apply(&self, stacks: &mut [Vec<char>], inst: &MoveInstruction) {
+            stacks[inst.origin - 1].reverse();
+            CM9001.apply(stacks, inst);
+        }
+
+        fn undo(&self, stacks: &mut [Vec<char>], inst: &MoveInstruction) {
+            CM9001.undo(stacks, inst);
+            stacks[inst.origin - 1].reverse();
+        }
+    }
+
+    #[test]
+    fn custom_mover() {
+        let (stacks, _) = parse_str(SAMPLE).unwrap();
+        let mut cargo = Cargo::new(stacks, Box::new(ReverseAndMove));
+        cargo.move_cargo(&MoveInstruction::new(1, 1, 2)).unwrap();
+        assert_eq!(cargo.get_top_string(), "NZP");
+    }
+
+    #[test]
+    fn cm9001_preserves_block_order() {
+        let stacks = vec![vec!['A', 'B', 'C', 'D'], vec!['X']];
+        let inst = MoveInstruction::new(3, 1, 2);
+
+        let mut cargo = Cargo::new(stacks.clone(), Box::new(CM9001));
+        cargo.move_cargo(&inst).unwrap();
+        assert_eq!(cargo.stacks, vec![vec!['A'], vec!['X', 'B', 'C', 'D']]);
+
+        let mut cargo = Cargo::new(stacks, Box::new(CM9000));
+        cargo.move_cargo(&inst).unwrap();
+        assert_eq!(cargo.stacks, vec![vec!['A'], vec!['X', 'D', 'C', 'B']]);
+    }
+
+    #[test]
+    fn whole_stack_moves() {
+        let stacks = vec![vec!['A', 'B', 'C'], vec![]];
+        let inst = MoveInstruction::new(3, 1, 2);
+
+        let mut cargo = Cargo::new(stacks.clone(), Box::new(CM9001));
+        cargo.move_cargo(&inst).unwrap();
+        assert_eq!(cargo.stacks, vec![vec![], vec!['A', 'B', 'C']]);
+
+        let mut cargo = Cargo::new(stacks, Box::new(CM9000));
+        cargo.move_cargo(&inst).unwrap();
+        assert_eq!(cargo.stacks, vec![vec![], vec!['C', 'B', 'A']]);
+    }
+
+    #[test]
+    fn undo_to_initial_stacks() {
+        let (stacks, instructions) = parse_str(SAMPLE).unwrap();
+        let movers: [Box<dyn Mover>; 3] =
+            [Box::new(CM9000), Box::new(CM9001), Box::new(ReverseAndMove)];
+        for mover in movers {
+            let mut cargo = Cargo::new(stacks.clone(), mover);
+            for inst in &instructions[..2] {
+                cargo.move_cargo(inst).unwrap();
+            }
+            assert_eq!(cargo.history_len(), 2);
+
+            assert!(cargo.undo().is_some());
+            assert!(cargo.undo().is_some());
+            assert!(cargo.undo().is_none());
+            assert_eq!(cargo.history_len(), 0);
+            assert_eq!(cargo.stacks, stacks);
+        }
+
+        // Undoing part of the way lands on the intermediate state
+        let mut cargo = Cargo::new(stacks.clone(), Box::new(CM9000));
+        for inst in &instructions {
+            cargo.move_cargo(inst).unwrap();
+        }
+        cargo.undo();
+        let mut expected = Cargo::new(stacks, Box::new(CM9000));
+        for inst in &instructions[..instructions.len() - 1] {
+            expected.move_cargo(inst).unwrap();
+        }
+        assert_eq!(cargo.stacks, expected.stacks);
+    }
+
+    #[test]
+    fn invalid_moves() {
+        let (stacks, _) = parse_str(SAMPLE).unwrap();
+        for (amount, origin, destination) in [(1, 0, 1), (1, 1, 4), (3, 1, 2), (4, 2, 1)] {
+            let instructions = [MoveInstruction::new(amount, origin, destination)];
+            assert!(part1(stacks.clone(), &instructions).is_err());
+            assert!(part2(stacks.clone(), &instructions).is_err());
+        }
+    }
+
+    #[test]
+    fn invalid_move_lines() {
+        let input = SAMPLE.replacen("move 1 from 2 to 1", "mvoe 1 from 2 to 1", 1);
+        let err = parse_str(&input).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert!(err.to_string().starts_with("Line 6: "), "{}", err);
+        assert!(
+            err.to_string().ends_with("in 'mvoe 1 from 2 to 1'"),
+            "{}",
+            err
+        );
+
+        let input = SAMPLE.replacen(
+            "move 3 from 1 to 3",
+            "move 99999999999999999999 from 1 to 3",
+            1,
+        );
+        assert!(parse_str(&input)
+            .unwrap_err()
+            .to_string()
+            .starts_with("Line 7: "));
+    }
+
+    #[test]
+    fn no_blank_line_after_labels() {
+        let input = SAMPLE.replacen(" 1   2   3 \n\n", " 1   2   3 \n", 1);
+        assert_eq!(input.lines().count(), SAMPLE.lines().count() - 1);
+        let (stacks, instructions) = parse_str(&input).unwrap();
+        assert_eq!(stacks, parse_str(SAMPLE).unwrap().0);
+        assert_eq!(instructions.len(), 4);
+        assert_eq!(
+            part1(stacks, &instructions).unwrap().get_top_string(),
+            "CMZ"
+        );
+    }
+
+    #[test]
+    fn two_digit_stack_labels() {
+        let sample = include_str!("../../../inputs/day05_test_11.in");
+        let (stacks, instructions) = parse_str(sample).unwrap();
+        assert_eq!(stacks.len(), 11);
+        assert_eq!(stacks[10], vec!['L', 'K']);
+
+        let cargo = Cargo::new(stacks.clone(), Box::new(CM9000));
+        let numbers_row = cargo.to_string().lines().last().unwrap().to_string();
+        assert_eq!(numbers_row, sample.lines().nth(2).unwrap().trim_end());
+
+        assert_eq!(
+            part1(stacks.clone(), &instructions)
+                .unwrap()
+                .get_top_string(),
+            "KBCDEFGHIL"
+        );
+        assert_eq!(
+            part2(stacks, &instructions).unwrap().get_top_string(),
+            "KBCDEFGHIJ"
+        );
+    }
+
+    #[test]
+    fn empty_input() {
+        assert!(matches!(Day05::parse(""), Err(AocError::Io(_))));
+    }
+}
@@ -0,0 +1,47 @@
+use std::collections::HashSet;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day06::{find_marker, find_marker_bytes};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+// The previous implementation, building a set for every window, kept as a baseline
+fn find_marker_set(stream: &[char], n: usize) -> Option<usize> {
+    for (i, window) in stream.windows(n).enumerate() {
+        let mut charset = HashSet::new();
+        if window.iter().all(|c| charset.insert(c)) {
+            return Some(i + n);
+        }
+    }
+
+    None
+}
+
+/// Pseudo-random stream over a 13 letter alphabet, so no 14 character marker is ever found
+/// and the whole stream gets scanned
+fn random_stream(len: usize) -> Vec<char> {
+    // Seeded, so every run scans the same stream
+    let mut rng = StdRng::seed_from_u64(2022);
+    (0..len).map(|_| rng.gen_range('a'..='m')).collect()
+}
+
+fn bench_markers(c: &mut Criterion) {
+    let stream = random_stream(1_000_000);
+
+    let mut group = c.benchmark_group("find_marker_14");
+    group.sample_size(10);
+    group.bench_function("frequency_window", |b| {
+        b.iter(|| find_marker(black_box(&stream), 14))
+    });
+    let stream_bytes: Vec<u8> = stream.iter().map(|&c| c as u8).collect();
+    group.bench_function("frequency_window_bytes", |b| {
+        b.iter(|| find_marker_bytes(black_box(&stream_bytes), 14))
+    });
+    group.bench_function("hash_set", |b| {
+        b.iter(|| find_marker_set(black_box(&stream), 14))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_markers);
+criterion_main!(benches);
@@ -0,0 +1,270 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use aoc_common::{read_to_string, solve, Answer, AocError, DayResult, Solution};
+
+pub fn parse_str(input: &str) -> Result<Vec<u8>, AocError> {
+    let stream = input.trim();
+    if let Some(c) = stream.chars().find(|c| !c.is_ascii()) {
+        return Err(AocError::Parse(format!(
+            "The stream should be ASCII, found '{}'",
+            c
+        )));
+    }
+
+    Ok(stream.as_bytes().to_vec())
+}
+
+pub fn parse_input<T: AsRef<Path>>(filename: T) -> Result<Vec<u8>, AocError> {
+    parse_str(&read_to_string(filename)?)
+}
+
+/// Occurrence counts of the items in a window
+trait WindowCounts<T> {
+    /// Counts one more `item`, returning how many the window now holds
+    fn increment(&mut self, item: T) -> u32;
+    /// Counts one less `item`, returning how many the window still holds
+    fn decrement(&mut self, item: T) -> u32;
+}
+
+impl WindowCounts<u8> for [u32; 256] {
+    fn increment(&mut self, item: u8) -> u32 {
+        self[item as usize] += 1;
+        self[item as usize]
+    }
+
+    fn decrement(&mut self, item: u8) -> u32 {
+        self[item as usize] -= 1;
+        self[item as usize]
+    }
+}
+
+/// Characters are too sparse for a table indexed by their value, so they're counted in a map
+impl WindowCounts<char> for HashMap<char, u32> {
+    fn increment(&mut self, item: char) -> u32 {
+        let count = self.entry(item).or_insert(0);
+        *count += 1;
+        *count
+    }
+
+    fn decrement(&mut self, item: char) -> u32 {
+        let count = self.get_mut(&item).expect("Item is in the window");
+        *count -= 1;
+        let count = *count;
+        if count == 0 {
+            self.remove(&item);
+        }
+        count
+    }
+}
+
+/// Iterator over the start of every window of `n` distinct items, sliding the window lazily so
+/// looking for the first one stops as soon as it's found
+struct DistinctWindows<'a, T, C> {
+    stream: &'a [T],
+    n: usize,
+    counts: C,
+    distinct: usize,
+    pos: usize,
+}
+
+fn distinct_windows<T, C>(stream: &[T], n: usize, counts: C) -> DistinctWindows<'_, T, C> {
+    DistinctWindows {
+        stream,
+        n,
+        counts,
+        distinct: 0,
+        pos: 0,
+    }
+}
+
+impl<T: Copy, C: WindowCounts<T>> Iterator for DistinctWindows<'_, T, C> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        // Every position starts an empty window
+        if self.n == 0 {
+            self.pos += 1;
+            return (self.pos <= self.stream.len() + 1).then_some(self.pos - 1);
+        }
+
+        // Slide the window one item at a time, counting how many of its items are distinct
+        while self.pos < self.stream.len() {
+            let i = self.pos;
+            self.pos += 1;
+
+            if self.counts.increment(self.stream[i]) == 1 {
+                self.distinct += 1;
+            }
+
+            // Drop the item that just left the window
+            if i >= self.n && self.counts.decrement(self.stream[i - self.n]) == 0 {
+                self.distinct -= 1;
+            }
+
+            if self.distinct == self.n {
+                return Some(i + 1 - self.n);
+            }
+        }
+
+        None
+    }
+}
+
+/// Finds the start of every window of `n` distinct characters, in stream order
+pub fn all_markers(stream: &[char], n: usize) -> Vec<usize> {
+    distinct_windows(stream, n, HashMap::new()).collect()
+}
+
+/// Finds the position right after the first window of `n` distinct characters
+pub fn find_marker(stream: &[char], n: usize) -> Option<usize> {
+    distinct_windows(stream, n, HashMap::new())
+        .next()
+        .map(|start| start + n)
+}
+
+/// Same as `find_marker`, for a stream of bytes
+pub fn find_marker_bytes(stream: &[u8], n: usize) -> Option<usize> {
+    distinct_windows(stream, n, [0u32; 256])
+        .next()
+        .map(|start| start + n)
+}
+
+pub fn find_first_marker(stream: &[u8]) -> Option<usize> {
+    find_marker_bytes(stream, 4)
+}
+
+pub fn find_first_message(stream: &[u8]) -> Option<usize> {
+    find_marker_bytes(stream, 14)
+}
+
+pub const INPUT_PATH: &str = "inputs/day06.in";
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+    const INPUT_PATH: &'static str = INPUT_PATH;
+    const PART1_LABEL: &'static str = "First marker position";
+    const PART2_LABEL: &'static str = "First message position";
+
+    type Input = Vec<u8>;
+
+    fn parse(s: &str) -> Result<Self::Input, AocError> {
+        parse_str(s)
+    }
+
+    fn part1(stream: &Self::Input) -> Result<Answer, AocError> {
+        let first_marker = find_first_marker(stream).ok_or(AocError::MissingField("marker"))?;
+        Ok(first_marker.into())
+    }
+
+    fn part2(stream: &Self::Input) -> Result<Answer, AocError> {
+        let first_message = find_first_message(stream).ok_or(AocError::MissingField("message"))?;
+        Ok(first_message.into())
+    }
+}
+
+/// Solves both parts against the committed input
+pub fn run() -> Result<DayResult, AocError> {
+    solve::<Day06, _>(INPUT_PATH)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("../../../inputs/day06_test.in");
+
+    #[test]
+    fn part1_sample() {
+        assert_eq!(find_first_marker(&parse_str(SAMPLE).unwrap()), Some(7));
+    }
+
+    #[test]
+    fn part2_sample() {
+        assert_eq!(find_first_message(&parse_str(SAMPLE).unwrap()), Some(19));
+    }
+
+    fn chars(s: &str) -> Vec<char> {
+        s.chars().collect()
+    }
+
+    #[test]
+    fn no_marker() {
+        assert_eq!(find_marker(&chars("abc"), 4), None);
+        assert_eq!(find_marker(&chars("aabbaabbaabb"), 4), None);
+        assert_eq!(find_marker(&chars("abcd"), 4), Some(4));
+        assert_eq!(find_marker(&chars("abcd"), 0), Some(0));
+        assert_eq!(find_marker_bytes(b"abc", 4), None);
+    }
+
+    #[test]
+    fn chars_and_bytes_agree() {
+        let stream = "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg";
+        for n in 1..=14 {
+            assert_eq!(
+                find_marker(&chars(stream), n),
+                find_marker_bytes(stream.as_bytes(), n)
+            );
+        }
+        // Non-ASCII characters are fine as chars
+        assert_eq!(find_marker(&chars("ééàç"), 3), Some(4));
+    }
+
+    #[test]
+    fn every_marker() {
+        // Windows of 3 distinct start at 0 (abc), 1 (bca), 2 (cab), 5 (bcd), 6 (cde) and 7 (dea)
+        let stream = chars("abcabbcdea");
+        assert_eq!(all_markers(&stream, 3), vec![0, 1, 2, 5, 6, 7]);
+        assert_eq!(find_marker(&stream, 3), Some(3));
+
+        assert_eq!(all_markers(&stream, 5), vec![5]);
+        assert!(all_markers(&stream, 6).is_empty());
+        assert!(all_markers(&chars("ab"), 3).is_empty());
+        assert_eq!(all_markers(&chars("ab"), 0), vec![0, 1, 2]);
+
+        // Characters far past ASCII don't need a table covering every value below them
+        assert_eq!(find_marker(&chars("a\u{10FFFF}a\u{10FFFF}b"), 3), Some(5));
+    }
+
+    #[test]
+    fn non_ascii_stream() {
+        assert!(parse_str("mjqjpqmgbljsphdztnvjfqwrcgsmlbé").is_err());
+    }
+
+    #[test]
+    fn other_samples() {
+        let samples = [
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+        ];
+        for (stream, marker, message) in samples {
+            let stream = parse_str(stream).unwrap();
+            assert_eq!(find_first_marker(&stream), Some(marker));
+            assert_eq!(find_first_message(&stream), Some(message));
+        }
+    }
+
+    #[test]
+    fn crlf_input() {
+        let stream = parse_str("mjqjpqmgbljsphdztnvjfqwrcgsmlb\r\n").unwrap();
+        assert!(!stream.contains(&b'\r'));
+        assert_eq!(find_first_marker(&stream), Some(7));
+    }
+
+    #[test]
+    fn empty_input() {
+        let input = Day06::parse("").unwrap();
+        assert!(matches!(
+            Day06::part1(&input),
+            Err(AocError::MissingField(_))
+        ));
+        assert!(matches!(
+            Day06::part2(&input),
+            Err(AocError::MissingField(_))
+        ));
+    }
+}
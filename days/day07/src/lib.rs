@@ -0,0 +1,623 @@
+use std::cell::RefCell;
+use std::io;
+use std::path::Path;
+use std::rc::Rc;
+
+use aoc_common::{read_to_string, solve, Answer, AocError, DayResult, PhaseTimer, Solution};
+
+#[derive(Clone)]
+pub enum FSNode {
+    Directory(Rc<RefCell<FSDirectory>>),
+    File(Rc<RefCell<FSFile>>),
+}
+
+pub struct FSDirectory {
+    parent: Option<Rc<RefCell<FSDirectory>>>,
+    name: String,
+    children: Vec<FSNode>,
+    // Total size of the files in the whole subtree, kept up to date as files are created
+    total_size: usize,
+}
+
+impl FSDirectory {
+    fn new(parent: Option<Rc<RefCell<FSDirectory>>>, name: String) -> Self {
+        FSDirectory {
+            parent,
+            name,
+            children: Vec::new(),
+            total_size: 0,
+        }
+    }
+}
+
+pub struct FSFile {
+    name: String,
+    size: usize,
+}
+
+impl FSFile {
+    fn new(name: String, size: usize) -> Self {
+        FSFile { name, size }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
+pub struct FileSystem {
+    total_space: usize,
+    root_dir: Rc<RefCell<FSDirectory>>,
+    current_dir: Rc<RefCell<FSDirectory>>,
+}
+
+impl std::fmt::Display for FileSystem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for entry in self.walk() {
+            let spaces = "  ".repeat(entry.depth);
+            match &entry.node {
+                FSNode::Directory(dir_rc) => {
+                    writeln!(f, "{}- {} (dir)", spaces, dir_rc.borrow().name)?;
+                }
+                FSNode::File(file) => {
+                    writeln!(
+                        f,
+                        "{}- {} (file, size={})",
+                        spaces,
+                        file.borrow().name,
+                        file.borrow().size
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A node met while walking the file system, along with where it was found
+pub struct WalkEntry {
+    pub node: FSNode,
+    /// Path of the directory holding the node, ending with '/', which is empty for the root
+    pub dir_path: Rc<str>,
+    /// How many directories the node is nested in, which is 0 for the root
+    pub depth: usize,
+}
+
+/// Depth-first walk of the file system from the root, visiting children in the order they were
+/// listed
+struct Walk {
+    stack: Vec<WalkEntry>,
+}
+
+impl Iterator for Walk {
+    type Item = WalkEntry;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let entry = self.stack.pop()?;
+        if let FSNode::Directory(dir_rc) = &entry.node {
+            // The root's name is already its path
+            let dir = dir_rc.borrow();
+            let path: Rc<str> = if dir.parent.is_none() {
+                Rc::from(dir.name.as_str())
+            } else {
+                Rc::from(format!("{}{}/", entry.dir_path, dir.name))
+            };
+
+            // Children are pushed in reverse so the first one comes out first
+            for child in dir.children.iter().rev() {
+                self.stack.push(WalkEntry {
+                    node: child.clone(),
+                    dir_path: path.clone(),
+                    depth: entry.depth + 1,
+                });
+            }
+        }
+
+        Some(entry)
+    }
+}
+
+impl Default for FileSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FileSystem {
+    pub fn new() -> Self {
+        let root_dir = Rc::new(RefCell::new(FSDirectory::new(None, String::from("/"))));
+        FileSystem {
+            total_space: 70000000,
+            root_dir: root_dir.clone(),
+            current_dir: root_dir,
+        }
+    }
+
+    fn find_subdirectory(
+        dir: &Rc<RefCell<FSDirectory>>,
+        dir_name: &str,
+    ) -> Option<Rc<RefCell<FSDirectory>>> {
+        for child_node in &dir.borrow().children {
+            match child_node {
+                FSNode::Directory(child_dir) => {
+                    if child_dir.borrow().name == dir_name {
+                        return Some(child_dir.clone());
+                    }
+                }
+                FSNode::File(_) => continue,
+            }
+        }
+
+        None
+    }
+
+    fn create_directory(&mut self, dir_name: &str) -> Rc<RefCell<FSDirectory>> {
+        let new_dir = Rc::new(RefCell::new(FSDirectory::new(
+            Some(self.current_dir.clone()),
+            dir_name.to_string(),
+        )));
+        self.current_dir
+            .borrow_mut()
+            .children
+            .push(FSNode::Directory(new_dir.clone()));
+        new_dir
+    }
+
+    /// Subdirectory of the current directory with the given name, which is created if it's missing,
+    /// since `cd` may have walked into it before any `ls` listed it
+    fn find_or_create_directory(&mut self, dir_name: &str) -> Rc<RefCell<FSDirectory>> {
+        match Self::find_subdirectory(&self.current_dir, dir_name) {
+            Some(dir) => dir,
+            None => self.create_directory(dir_name),
+        }
+    }
+
+    fn create_file(&mut self, file_name: &str, file_size: usize) -> Rc<RefCell<FSFile>> {
+        let new_file = Rc::new(RefCell::new(FSFile::new(file_name.to_string(), file_size)));
+        self.current_dir
+            .borrow_mut()
+            .children
+            .push(FSNode::File(new_file.clone()));
+
+        // Account for the new file in every directory up to the root
+        let mut dir = Some(self.current_dir.clone());
+        while let Some(dir_rc) = dir {
+            dir_rc.borrow_mut().total_size += file_size;
+            dir = dir_rc.borrow().parent.clone();
+        }
+
+        new_file
+    }
+
+    /// Walks a slash-delimited path, absolute if it starts with '/', creating missing directories.
+    /// Returns `None` if the path climbs above the root
+    fn change_directory(&mut self, path: &str) -> Option<()> {
+        if path.starts_with('/') {
+            self.current_dir = self.root_dir.clone();
+        }
+
+        for dir_name in path.split('/').filter(|c| !c.is_empty()) {
+            let next_dir = match dir_name {
+                "." => continue,
+                ".." => self.current_dir.borrow().parent.clone()?,
+                dir_name => self.find_or_create_directory(dir_name),
+            };
+
+            self.current_dir = next_dir;
+        }
+
+        Some(())
+    }
+
+    /// Replays the terminal output, failing on the 1-based line of the first one it can't follow
+    pub fn build_tree(&mut self, sh_lines: &[String]) -> Result<(), AocError> {
+        for (i, sh_line) in sh_lines.iter().enumerate() {
+            let sh_line = sh_line.trim();
+            let invalid_line = |reason: &str| {
+                AocError::Parse(format!("Line {}: {} in '{}'", i + 1, reason, sh_line))
+            };
+            if sh_line.is_empty() {
+                continue;
+            }
+
+            // Names are everything after the first field, so they may contain spaces
+            if let Some(command) = sh_line.strip_prefix("$ ") {
+                let (command_name, argument) = match command.split_once(' ') {
+                    Some((command_name, argument)) => (command_name, Some(argument)),
+                    None => (command, None),
+                };
+                match command_name {
+                    "cd" => {
+                        let dir_name = argument
+                            .ok_or_else(|| invalid_line("missing directory to change to"))?;
+                        self.change_directory(dir_name)
+                            .ok_or_else(|| invalid_line("can't go above the root directory"))?;
+                    }
+                    "ls" => continue,
+                    other => return Err(invalid_line(&format!("unknown command '{}'", other))),
+                }
+            } else {
+                let (field1, name) = sh_line
+                    .split_once(' ')
+                    .ok_or_else(|| invalid_line("expected a size or 'dir' before the name"))?;
+                match field1 {
+                    "dir" => _ = self.find_or_create_directory(name),
+                    number_str => {
+                        let file_size = number_str
+                            .parse()
+                            .map_err(|_| invalid_line("invalid file size"))?;
+                        _ = self.create_file(name, file_size);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn get_directory_size(&self, dir: &Rc<RefCell<FSDirectory>>) -> usize {
+        dir.borrow().total_size
+    }
+
+    /// Every file and directory, starting with the root and in depth-first order
+    pub fn walk(&self) -> impl Iterator<Item = WalkEntry> {
+        Walk {
+            stack: vec![WalkEntry {
+                node: FSNode::Directory(self.root_dir.clone()),
+                dir_path: Rc::from(""),
+                depth: 0,
+            }],
+        }
+    }
+
+    fn get_all_directories(&self) -> Vec<Rc<RefCell<FSDirectory>>> {
+        self.walk()
+            .filter_map(|entry| match entry.node {
+                FSNode::Directory(dir_rc) => Some(dir_rc),
+                FSNode::File(_) => None,
+            })
+            .collect()
+    }
+
+    /// Full paths of the files matching the predicate, in depth-first order
+    pub fn find_files(&self, predicate: impl Fn(&FSFile) -> bool) -> Vec<String> {
+        self.walk()
+            .filter_map(|entry| match &entry.node {
+                FSNode::File(file) if predicate(&file.borrow()) => {
+                    Some(format!("{}{}", entry.dir_path, file.borrow().name))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Total size of the file or directory at the given slash-delimited path, resolved from the root
+    pub fn size_of_path(&self, path: &str) -> Option<usize> {
+        let mut components: Vec<&str> = path.split('/').filter(|c| !c.is_empty()).collect();
+        let last_component = components.pop();
+
+        let mut dir = self.root_dir.clone();
+        for dir_name in components {
+            dir = Self::find_subdirectory(&dir, dir_name)?;
+        }
+
+        let Some(name) = last_component else {
+            return Some(self.get_directory_size(&dir));
+        };
+
+        // The last component may name either a directory or a file
+        let dir = dir.borrow();
+        dir.children.iter().find_map(|child_node| match child_node {
+            FSNode::Directory(child_dir) if child_dir.borrow().name == name => {
+                Some(self.get_directory_size(child_dir))
+            }
+            FSNode::File(file) if file.borrow().name == name => Some(file.borrow().size),
+            _ => None,
+        })
+    }
+
+    /// Total size of every directory, starting with the root and in depth-first order
+    pub fn all_directory_sizes(&self) -> Vec<usize> {
+        self.all_directory_sizes_timed(&mut PhaseTimer::disabled())
+    }
+
+    fn all_directory_sizes_timed(&self, timer: &mut PhaseTimer) -> Vec<usize> {
+        let directories = timer.time("collecting directories", || self.get_all_directories());
+        timer.time("reading directory sizes", || {
+            directories
+                .iter()
+                .map(|dir| self.get_directory_size(dir))
+                .collect()
+        })
+    }
+}
+
+pub fn parse_str(input: &str) -> Vec<String> {
+    input.lines().map(String::from).collect()
+}
+
+pub fn parse_input<T: AsRef<Path>>(filename: T) -> io::Result<Vec<String>> {
+    Ok(parse_str(&read_to_string(filename)?))
+}
+
+/// Builds the file system from the terminal output, timing each step with `timer`
+pub fn parse_timed(input: &str, timer: &mut PhaseTimer) -> Result<FileSystem, AocError> {
+    let sh_lines = timer.time("splitting lines", || parse_str(input));
+
+    let mut file_system = FileSystem::new();
+    timer.time("building tree", || file_system.build_tree(&sh_lines))?;
+
+    Ok(file_system)
+}
+
+pub fn part1(file_system: &FileSystem) -> usize {
+    part1_timed(file_system, &mut PhaseTimer::disabled())
+}
+
+/// Same as `part1`, timing each step with `timer`
+pub fn part1_timed(file_system: &FileSystem, timer: &mut PhaseTimer) -> usize {
+    let sizes = file_system.all_directory_sizes_timed(timer);
+    timer.time("summing small directories", || {
+        sizes.into_iter().filter(|&s| s < 100000).sum()
+    })
+}
+
+/// Space the update needs to be installed
+const UPDATE_SIZE: usize = 30000000;
+
+/// Size of the smallest directory to remove to make room for the update. Fails if the files
+/// don't fit the disk, or if there's already enough free space and nothing needs removing
+pub fn part2(file_system: &FileSystem) -> Result<usize, AocError> {
+    part2_timed(file_system, &mut PhaseTimer::disabled())
+}
+
+/// Same as `part2`, timing each step with `timer`
+pub fn part2_timed(file_system: &FileSystem, timer: &mut PhaseTimer) -> Result<usize, AocError> {
+    let root_size = file_system.get_directory_size(&file_system.root_dir);
+    let free_space_size = file_system
+        .total_space
+        .checked_sub(root_size)
+        .ok_or_else(|| {
+            AocError::Parse(format!(
+                "Files take {} but the disk only holds {}",
+                root_size, file_system.total_space
+            ))
+        })?;
+    let required_free_size = UPDATE_SIZE.checked_sub(free_space_size).ok_or_else(|| {
+        AocError::Parse(format!(
+            "Enough space is already free for the update ({} of {})",
+            free_space_size, UPDATE_SIZE
+        ))
+    })?;
+
+    // The root is at least as large as the space to free, so some directory always qualifies
+    let sizes = file_system.all_directory_sizes_timed(timer);
+    timer.time("finding directory to remove", || {
+        sizes
+            .into_iter()
+            .filter(|&s| s >= required_free_size)
+            .min()
+            .ok_or(AocError::MissingField("directory to remove"))
+    })
+}
+
+pub const INPUT_PATH: &str = "inputs/day07.in";
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+    const INPUT_PATH: &'static str = INPUT_PATH;
+    const PART1_LABEL: &'static str = "File sizes sum";
+    const PART2_LABEL: &'static str = "Size of removed directory";
+
+    type Input = FileSystem;
+
+    fn parse(s: &str) -> Result<Self::Input, AocError> {
+        parse_timed(s, &mut PhaseTimer::disabled())
+    }
+
+    fn part1(file_system: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part1(file_system).into())
+    }
+
+    fn part2(file_system: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part2(file_system)?.into())
+    }
+}
+
+/// Solves both parts against the committed input
+pub fn run() -> Result<DayResult, AocError> {
+    solve::<Day07, _>(INPUT_PATH)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("../../../inputs/day07_test.in");
+
+    fn build_sample() -> FileSystem {
+        let mut file_system = FileSystem::new();
+        file_system.build_tree(&parse_str(SAMPLE)).unwrap();
+        file_system
+    }
+
+    #[test]
+    fn part1_sample() {
+        assert_eq!(part1(&build_sample()), 95437);
+    }
+
+    #[test]
+    fn part2_sample() {
+        assert_eq!(part2(&build_sample()).unwrap(), 24933642);
+    }
+
+    #[test]
+    fn directory_sizes() {
+        // Directories in depth-first order: /, a, e and d
+        assert_eq!(
+            build_sample().all_directory_sizes(),
+            vec![48381165, 94853, 584, 24933642]
+        );
+    }
+
+    #[test]
+    fn timed_phases() {
+        let mut timer = PhaseTimer::new(true);
+        let file_system = parse_timed(SAMPLE, &mut timer).unwrap();
+        assert_eq!(part1_timed(&file_system, &mut timer), 95437);
+        assert_eq!(part2_timed(&file_system, &mut timer).unwrap(), 24933642);
+
+        let names: Vec<_> = timer.phases().iter().map(|(name, _)| *name).collect();
+        assert_eq!(
+            names,
+            [
+                "splitting lines",
+                "building tree",
+                "collecting directories",
+                "reading directory sizes",
+                "summing small directories",
+                "collecting directories",
+                "reading directory sizes",
+                "finding directory to remove"
+            ]
+        );
+    }
+
+    #[test]
+    fn path_sizes() {
+        let file_system = build_sample();
+        assert_eq!(file_system.size_of_path("/"), Some(48381165));
+        assert_eq!(file_system.size_of_path("/a"), Some(94853));
+        assert_eq!(file_system.size_of_path("/a/e"), Some(584));
+        assert_eq!(file_system.size_of_path("/a/e/"), Some(584));
+        assert_eq!(file_system.size_of_path("/a/h.lst"), Some(62596));
+        assert_eq!(file_system.size_of_path("/a/x"), None);
+        assert_eq!(file_system.size_of_path("/b.txt/e"), None);
+    }
+
+    #[test]
+    fn walk_order() {
+        let file_system = build_sample();
+        let walked: Vec<_> = file_system
+            .walk()
+            .map(|entry| {
+                let name = match &entry.node {
+                    FSNode::Directory(dir_rc) => dir_rc.borrow().name.clone(),
+                    FSNode::File(file) => file.borrow().name.clone(),
+                };
+                (format!("{}{}", entry.dir_path, name), entry.depth)
+            })
+            .collect();
+
+        assert_eq!(walked.len(), 14);
+        assert_eq!(walked[0], ("/".to_string(), 0));
+        assert_eq!(walked[1], ("/a".to_string(), 1));
+        assert_eq!(walked[2], ("/a/e".to_string(), 2));
+        assert_eq!(walked[3], ("/a/e/i".to_string(), 3));
+        assert_eq!(walked[13], ("/d/k".to_string(), 2));
+    }
+
+    #[test]
+    fn big_files() {
+        let file_system = build_sample();
+        assert_eq!(
+            file_system.find_files(|file| file.size() > 1000000),
+            vec!["/b.txt", "/c.dat", "/d/j", "/d/d.log", "/d/d.ext", "/d/k"]
+        );
+        assert_eq!(
+            file_system.find_files(|file| file.name().ends_with(".lst")),
+            vec!["/a/h.lst"]
+        );
+    }
+
+    #[test]
+    fn path_changes() {
+        let sh_lines = parse_str(
+            "$ cd /a/b\n$ ls\n10 x\n$ cd ../c/\n$ ls\n20 y\n\
+             $ cd /\n$ cd a/./b\n$ ls\n300 z",
+        );
+        let mut file_system = FileSystem::new();
+        file_system.build_tree(&sh_lines).unwrap();
+
+        assert_eq!(
+            file_system.to_string(),
+            "- / (dir)\n  - a (dir)\n    - b (dir)\n      - x (file, size=10)\n      \
+             - z (file, size=300)\n    - c (dir)\n      - y (file, size=20)\n"
+        );
+        // Directories in depth-first order: /, a, b and c
+        assert_eq!(file_system.all_directory_sizes(), vec![330, 330, 310, 20]);
+    }
+
+    #[test]
+    fn listed_after_cd() {
+        // `cd` creates a and b before the root's `ls` lists a, which must not add a second a
+        let sh_lines =
+            parse_str("$ cd /a/b\n$ ls\n10 x\n$ cd /\n$ ls\ndir a\n5 y\n$ cd a\n$ ls\ndir b");
+        let mut file_system = FileSystem::new();
+        file_system.build_tree(&sh_lines).unwrap();
+
+        assert_eq!(
+            file_system.to_string(),
+            "- / (dir)\n  - a (dir)\n    - b (dir)\n      - x (file, size=10)\n  \
+             - y (file, size=5)\n"
+        );
+        assert_eq!(file_system.size_of_path("/a"), Some(10));
+        assert_eq!(file_system.size_of_path("/"), Some(15));
+    }
+
+    #[test]
+    fn spaced_names() {
+        let sh_lines = parse_str(
+            "$ cd /\n$ ls\ndir my docs\n100 my report.txt\n\
+             $ cd my docs\n$ ls\n2000 old notes.md\n$ cd ..",
+        );
+        let mut file_system = FileSystem::new();
+        file_system.build_tree(&sh_lines).unwrap();
+
+        assert_eq!(
+            file_system.to_string(),
+            "- / (dir)\n  - my docs (dir)\n    - old notes.md (file, size=2000)\n  \
+             - my report.txt (file, size=100)\n"
+        );
+        assert_eq!(part1(&file_system), 2100 + 2000);
+    }
+
+    #[test]
+    fn invalid_lines() {
+        for (input, line) in [
+            ("$ cd /\n$ ls\nfoo", "Line 3"),
+            ("$ cd /\n$ cd", "Line 2"),
+            ("$ cd /\n$ rm -rf a", "Line 2"),
+            ("$ ls\nbig a.txt", "Line 2"),
+            ("$ cd /\n$ cd ..", "Line 2"),
+        ] {
+            match FileSystem::new().build_tree(&parse_str(input)) {
+                Err(AocError::Parse(message)) => assert!(message.starts_with(line), "{}", message),
+                other => panic!("Expected a parse error for {:?}, got {:?}", input, other),
+            }
+        }
+    }
+
+    #[test]
+    fn not_enough_to_free() {
+        let file_system = Day07::parse("$ cd /\n$ ls\n100 a.txt").unwrap();
+        assert!(matches!(part2(&file_system), Err(AocError::Parse(_))));
+
+        let file_system = Day07::parse("$ cd /\n$ ls\n80000000 a.txt").unwrap();
+        assert!(matches!(part2(&file_system), Err(AocError::Parse(_))));
+    }
+
+    #[test]
+    fn empty_input() {
+        let file_system = Day07::parse("").unwrap();
+        assert_eq!(Day07::part1(&file_system).unwrap(), Answer::from(0usize));
+        assert!(Day07::part2(&file_system).is_err());
+    }
+}
@@ -0,0 +1,59 @@
+use aoc_common::Grid;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day08::{part1, TreeGrid};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+// The previous implementation, scanning every direction from every tree, kept as a baseline
+fn visible_trees_scan(grid: &Grid<usize>) -> usize {
+    let mut visible_count = 0;
+    for i in 0..grid.height() {
+        for j in 0..grid.width() {
+            let is_on_edge = i == 0 || i == grid.height() - 1 || j == 0 || j == grid.width() - 1;
+            if is_on_edge {
+                visible_count += 1;
+                continue;
+            }
+
+            let cur_height = grid[(i, j)];
+            let north_visible = (0..i).all(|pos_i| grid[(pos_i, j)] < cur_height);
+            let east_visible = (j + 1..grid.width()).all(|pos_j| grid[(i, pos_j)] < cur_height);
+            let south_visible = (i + 1..grid.height()).all(|pos_i| grid[(pos_i, j)] < cur_height);
+            let west_visible = (0..j).all(|pos_j| grid[(i, pos_j)] < cur_height);
+            if north_visible || east_visible || south_visible || west_visible {
+                visible_count += 1;
+            }
+        }
+    }
+
+    visible_count
+}
+
+/// Pseudo-random square grid of tree heights
+fn random_grid(size: usize) -> Grid<usize> {
+    // Seeded, so every run measures the same forest
+    let mut rng = StdRng::seed_from_u64(2022);
+    let rows = (0..size)
+        .map(|_| (0..size).map(|_| rng.gen_range(0..10)).collect())
+        .collect();
+
+    Grid::new(rows).unwrap()
+}
+
+fn bench_visibility(c: &mut Criterion) {
+    let grid = random_grid(500);
+    let tree_grid = TreeGrid::new(random_grid(500));
+
+    let mut group = c.benchmark_group("visible_trees_500");
+    group.sample_size(10);
+    group.bench_function("running_maxima", |b| {
+        b.iter(|| part1(black_box(&tree_grid)))
+    });
+    group.bench_function("directional_scan", |b| {
+        b.iter(|| visible_trees_scan(black_box(&grid)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_visibility);
+criterion_main!(benches);
@@ -0,0 +1,360 @@
+use std::collections::HashSet;
+use std::path::Path;
+use std::str::FromStr;
+
+use aoc_common::{read_to_string, solve, Answer, AocError, DayResult, Direction, Grid, Solution};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+pub struct TreeGrid {
+    grid: Grid<usize>,
+}
+
+impl TreeGrid {
+    pub fn new(grid: Grid<usize>) -> Self {
+        TreeGrid { grid }
+    }
+
+    fn get_visible_trees_count(&self) -> usize {
+        self.visible_positions().len()
+    }
+
+    /// Positions `(row, column)` of every tree visible from outside the grid
+    pub fn visible_positions(&self) -> HashSet<(usize, usize)> {
+        let (height, width) = (self.grid.height(), self.grid.width());
+        let mut visible = vec![false; width * height];
+
+        // A tree is visible from a side if it's taller than the running maximum coming from
+        // that side, where `None` means no trees were seen yet
+        for i in 0..height {
+            let mut west_max = None;
+            for j in 0..width {
+                let cur_height = Some(self.grid[(i, j)]);
+                if cur_height > west_max {
+                    visible[i * width + j] = true;
+                    west_max = cur_height;
+                }
+            }
+
+            let mut east_max = None;
+            for j in (0..width).rev() {
+                let cur_height = Some(self.grid[(i, j)]);
+                if cur_height > east_max {
+                    visible[i * width + j] = true;
+                    east_max = cur_height;
+                }
+            }
+        }
+
+        // Sweep whole rows at a time, keeping the running maximum of every column
+        let mut north_max = vec![None; width];
+        for i in 0..height {
+            for (j, column_max) in north_max.iter_mut().enumerate() {
+                let cur_height = Some(self.grid[(i, j)]);
+                if cur_height > *column_max {
+                    visible[i * width + j] = true;
+                    *column_max = cur_height;
+                }
+            }
+        }
+
+        let mut south_max = vec![None; width];
+        for i in (0..height).rev() {
+            for (j, column_max) in south_max.iter_mut().enumerate() {
+                let cur_height = Some(self.grid[(i, j)]);
+                if cur_height > *column_max {
+                    visible[i * width + j] = true;
+                    *column_max = cur_height;
+                }
+            }
+        }
+
+        visible
+            .into_iter()
+            .enumerate()
+            .filter(|&(_, v)| v)
+            .map(|(idx, _)| (idx / width, idx % width))
+            .collect()
+    }
+
+    /// Product of the viewing distances in the four directions from the tree at `(i, j)`.
+    /// Fails if the position is outside the grid
+    pub fn scenic_score(&self, i: usize, j: usize) -> Result<usize, AocError> {
+        let Some(&cur_height) = self.grid.get(i, j) else {
+            return Err(AocError::OutOfRange(format!(
+                "Position ({}, {}) is outside the {}x{} grid",
+                i,
+                j,
+                self.grid.height(),
+                self.grid.width()
+            )));
+        };
+
+        // Test if the tree is on the edge of the grid, which isn't empty since it holds (i, j)
+        let is_on_edge =
+            i == 0 || i == self.grid.height() - 1 || j == 0 || j == self.grid.width() - 1;
+        if is_on_edge {
+            return Ok(0);
+        }
+
+        // Calculate total viewing score from the distances in every direction
+        let distances =
+            Direction::all().map(|direction| self.viewing_distance(i, j, cur_height, direction));
+        view_score_product(distances)
+    }
+
+    /// Amount of trees seen from the tree at `(i, j)`, of height `cur_height`, looking in
+    /// `direction`, up to the edge or the first tree at least as tall as it
+    fn viewing_distance(
+        &self,
+        i: usize,
+        j: usize,
+        cur_height: usize,
+        direction: Direction,
+    ) -> usize {
+        let (i_delta, j_delta) = direction.delta();
+
+        let mut distance = 0;
+        let (mut pos_i, mut pos_j) = (i, j);
+        while let (Some(next_i), Some(next_j)) = (
+            pos_i.checked_add_signed(i_delta),
+            pos_j.checked_add_signed(j_delta),
+        ) {
+            let Some(&height) = self.grid.get(next_i, next_j) else {
+                break;
+            };
+
+            distance += 1;
+            if height >= cur_height {
+                break;
+            }
+            (pos_i, pos_j) = (next_i, next_j);
+        }
+
+        distance
+    }
+
+    fn get_view_scores(&self) -> Result<Vec<Vec<usize>>, AocError> {
+        let mut view_scores = vec![vec![0; self.grid.width()]; self.grid.height()];
+
+        // Every row only reads the grid, so they can be scored independently
+        #[cfg(feature = "rayon")]
+        let score_rows = view_scores.par_iter_mut();
+        #[cfg(not(feature = "rayon"))]
+        let score_rows = view_scores.iter_mut();
+
+        score_rows.enumerate().try_for_each(|(i, score_row)| {
+            for (j, score) in score_row.iter_mut().enumerate() {
+                *score = self.scenic_score(i, j)?;
+            }
+            Ok::<_, AocError>(())
+        })?;
+
+        Ok(view_scores)
+    }
+}
+
+/// Multiplies the viewing distances, failing instead of wrapping around if the product is too large
+fn view_score_product(distances: [usize; 4]) -> Result<usize, AocError> {
+    distances
+        .into_iter()
+        .try_fold(1usize, |acc, distance| acc.checked_mul(distance))
+        .ok_or_else(|| AocError::Overflow(format!("View score of {:?} overflows", distances)))
+}
+
+impl FromStr for TreeGrid {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(TreeGrid::new(parse_str(s)?))
+    }
+}
+
+pub fn parse_str(input: &str) -> Result<Grid<usize>, AocError> {
+    Grid::from_str_with(input, |_, c| {
+        c.to_digit(10)
+            .map(|h| h as usize)
+            .ok_or_else(|| AocError::Parse(format!("Failed to parse digit '{}'", c)))
+    })
+}
+
+pub fn parse_input<T: AsRef<Path>>(filename: T) -> Result<Grid<usize>, AocError> {
+    parse_str(&read_to_string(filename)?)
+}
+
+/// Parses a grid whose heights can have several digits, separated by whitespace
+pub fn parse_str_spaced(input: &str) -> Result<Grid<usize>, AocError> {
+    let rows = input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            line.split_whitespace()
+                .map(|height| {
+                    height.parse().map_err(|_| {
+                        AocError::Parse(format!("Line {}: invalid height '{}'", i + 1, height))
+                    })
+                })
+                .collect()
+        })
+        .collect::<Result<_, _>>()?;
+
+    Grid::new(rows)
+}
+
+pub fn parse_input_spaced<T: AsRef<Path>>(filename: T) -> Result<Grid<usize>, AocError> {
+    parse_str_spaced(&read_to_string(filename)?)
+}
+
+pub fn part1(tree_grid: &TreeGrid) -> usize {
+    tree_grid.get_visible_trees_count()
+}
+
+pub fn part2(tree_grid: &TreeGrid) -> Result<usize, AocError> {
+    Ok(*tree_grid.get_view_scores()?.iter().flatten().max().unwrap())
+}
+
+pub const INPUT_PATH: &str = "inputs/day08.in";
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u32 = 8;
+    const INPUT_PATH: &'static str = INPUT_PATH;
+    const PART1_LABEL: &'static str = "Amount of visible trees";
+    const PART2_LABEL: &'static str = "Max view score";
+
+    type Input = TreeGrid;
+
+    fn parse(s: &str) -> Result<Self::Input, AocError> {
+        s.parse()
+    }
+
+    fn part1(tree_grid: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part1(tree_grid).into())
+    }
+
+    fn part2(tree_grid: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part2(tree_grid)?.into())
+    }
+}
+
+/// Solves both parts against the committed input
+pub fn run() -> Result<DayResult, AocError> {
+    solve::<Day08, _>(INPUT_PATH)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("../../../inputs/day08_test.in");
+
+    #[test]
+    fn part1_sample() {
+        let tree_grid = TreeGrid::new(parse_str(SAMPLE).unwrap());
+        assert_eq!(part1(&tree_grid), 21);
+    }
+
+    #[test]
+    fn part2_sample() {
+        let tree_grid = TreeGrid::new(parse_str(SAMPLE).unwrap());
+        assert_eq!(part2(&tree_grid).unwrap(), 8);
+    }
+
+    #[test]
+    fn parse_with_from_str() {
+        let tree_grid: TreeGrid = SAMPLE.parse().unwrap();
+        assert_eq!(part1(&tree_grid), 21);
+        assert!("303\n2x5".parse::<TreeGrid>().is_err());
+    }
+
+    #[test]
+    fn thin_grids() {
+        // Every tree of a single row or column is on the edge
+        assert_eq!(part1(&TreeGrid::new(parse_str("30373").unwrap())), 5);
+        assert_eq!(part1(&TreeGrid::new(parse_str("3\n0\n3").unwrap())), 3);
+        assert_eq!(part1(&TreeGrid::new(parse_str("5").unwrap())), 1);
+    }
+
+    #[test]
+    fn multi_digit_heights() {
+        // Scaling every height keeps their order, so the answers don't change
+        let spaced_sample: String = SAMPLE
+            .lines()
+            .map(|line| {
+                let heights: Vec<_> = line
+                    .chars()
+                    .map(|c| (c.to_digit(10).unwrap() * 12).to_string())
+                    .collect();
+                heights.join(" ") + "\n"
+            })
+            .collect();
+        let tree_grid = TreeGrid::new(parse_str_spaced(&spaced_sample).unwrap());
+        assert_eq!(part1(&tree_grid), 21);
+        assert_eq!(part2(&tree_grid).unwrap(), 8);
+
+        let tree_grid = TreeGrid::new(parse_str_spaced("12 7 103\n10 11 10\n99 10 12").unwrap());
+        assert_eq!(part1(&tree_grid), 9);
+        assert_eq!(part2(&tree_grid).unwrap(), 1);
+
+        assert!(parse_str_spaced("12 7\n10 x").is_err());
+        assert!(parse_str_spaced("12 7\n10").is_err());
+    }
+
+    #[test]
+    fn visible_tree_positions() {
+        let tree_grid = TreeGrid::new(parse_str(SAMPLE).unwrap());
+        let visible = tree_grid.visible_positions();
+        assert_eq!(visible.len(), 21);
+
+        // Interior trees from the puzzle description
+        assert!(visible.contains(&(1, 1)) && visible.contains(&(1, 2)));
+        assert!(!visible.contains(&(1, 3)) && !visible.contains(&(3, 3)));
+    }
+
+    #[test]
+    fn single_scenic_score() {
+        let tree_grid = TreeGrid::new(parse_str(SAMPLE).unwrap());
+        assert_eq!(tree_grid.scenic_score(3, 2).unwrap(), 8);
+        assert_eq!(tree_grid.scenic_score(1, 2).unwrap(), 4);
+        assert_eq!(tree_grid.scenic_score(0, 2).unwrap(), 0);
+        assert_eq!(tree_grid.scenic_score(4, 4).unwrap(), 0);
+
+        for (i, j) in [(5, 0), (0, 5), (usize::MAX, usize::MAX)] {
+            assert!(matches!(
+                tree_grid.scenic_score(i, j),
+                Err(AocError::OutOfRange(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn view_score_overflow() {
+        assert_eq!(view_score_product([1, 2, 3, 4]).unwrap(), 24);
+        assert_eq!(
+            view_score_product([0, usize::MAX, usize::MAX, 2]).unwrap(),
+            0
+        );
+        assert!(view_score_product([1 << 20, 1 << 20, 1 << 20, 1 << 30]).is_err());
+        assert!(matches!(
+            view_score_product([usize::MAX, 2, 1, 1]),
+            Err(AocError::Overflow(_))
+        ));
+    }
+
+    #[test]
+    fn view_scores_match_single_queries() {
+        let tree_grid = TreeGrid::new(parse_str(SAMPLE).unwrap());
+        let view_scores = tree_grid.get_view_scores().unwrap();
+        for (i, score_row) in view_scores.iter().enumerate() {
+            for (j, &score) in score_row.iter().enumerate() {
+                assert_eq!(score, tree_grid.scenic_score(i, j).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn empty_input() {
+        assert!(matches!(Day08::parse(""), Err(AocError::Parse(_))));
+    }
+}
@@ -0,0 +1,405 @@
+use std::collections::HashSet;
+use std::path::Path;
+use std::str::FromStr;
+
+use aoc_common::{read_to_string, solve, Answer, AocError, DayResult, Direction, Point, Solution};
+
+/// How the head moves at each step: along a direction, or diagonally between a vertical and a
+/// horizontal one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Motion {
+    Straight(Direction),
+    Diagonal(Direction, Direction),
+}
+
+impl Motion {
+    /// Offset of a single step, with y pointing up
+    pub fn offset(&self) -> Point<isize> {
+        let step = |direction: Direction| {
+            let (row_delta, col_delta) = direction.delta();
+            Point::new(col_delta, -row_delta)
+        };
+
+        match *self {
+            Motion::Straight(direction) => step(direction),
+            Motion::Diagonal(vertical, horizontal) => step(vertical) + step(horizontal),
+        }
+    }
+}
+
+impl FromStr for Motion {
+    type Err = AocError;
+
+    /// Parses `U`, `D`, `L` and `R`, or a vertical direction followed by a horizontal one like
+    /// `UL`, in either case
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || AocError::Parse(format!("Can't convert string '{}' to direction", s));
+        if s.len() > 2 || !s.is_char_boundary(1) {
+            return Err(invalid());
+        }
+
+        let (first, second) = s.split_at(1);
+        if second.is_empty() {
+            return Ok(Motion::Straight(first.parse()?));
+        }
+
+        match (first.parse()?, second.parse()?) {
+            (
+                vertical @ (Direction::Up | Direction::Down),
+                horizontal @ (Direction::Left | Direction::Right),
+            ) => Ok(Motion::Diagonal(vertical, horizontal)),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// Where a knot ends up after its leader moved: it stays put while they're touching
+/// (diagonals included), otherwise it takes one step towards the leader on each axis
+fn follow(leader: Point<isize>, follower: Point<isize>) -> Point<isize> {
+    if follower.chebyshev(&leader) >= 2 {
+        follower + follower.signum_towards(&leader)
+    } else {
+        follower
+    }
+}
+
+pub struct RopeGrid {
+    segments: Vec<Point<isize>>,
+    tail_visited_set: HashSet<Point<isize>>,
+    head_visited_set: HashSet<Point<isize>>,
+    head_steps: usize,
+    knot_paths: Option<Vec<HashSet<Point<isize>>>>,
+}
+
+impl RopeGrid {
+    /// Creates a rope of `n_segments` knots, all at the origin. Panics if there are no knots
+    pub fn new(n_segments: usize) -> Self {
+        assert!(n_segments > 0, "A rope needs at least one knot");
+        let segments = vec![Point::default(); n_segments];
+
+        let mut tail_visited_set = HashSet::new();
+        tail_visited_set.insert(Point::default());
+
+        RopeGrid {
+            segments,
+            head_visited_set: tail_visited_set.clone(),
+            tail_visited_set,
+            head_steps: 0,
+            knot_paths: None,
+        }
+    }
+
+    /// Creates a rope that also records the positions visited by every knot, not just the tail
+    pub fn with_knot_paths(n_segments: usize) -> Self {
+        let mut rope_grid = Self::new(n_segments);
+        rope_grid.knot_paths = Some(vec![HashSet::from([Point::default()]); n_segments]);
+
+        rope_grid
+    }
+
+    pub fn do_movement(&mut self, movement: &(Motion, usize)) {
+        let move_offset = movement.0.offset();
+
+        for _ in 0..movement.1 {
+            self.segments[0] += move_offset;
+            self.head_visited_set.insert(self.segments[0]);
+            self.head_steps += 1;
+
+            for i in 1..self.segments.len() {
+                self.segments[i] = follow(self.segments[i - 1], self.segments[i]);
+            }
+
+            // With a single knot, the head is also the tail
+            self.tail_visited_set.insert(*self.segments.last().unwrap());
+
+            if let Some(knot_paths) = &mut self.knot_paths {
+                for (path, &segment) in knot_paths.iter_mut().zip(&self.segments) {
+                    path.insert(segment);
+                }
+            }
+        }
+    }
+
+    /// Total amount of unit steps the head took
+    pub fn head_steps(&self) -> usize {
+        self.head_steps
+    }
+
+    /// Amount of distinct cells the head visited, including the starting one
+    pub fn head_distinct_cells(&self) -> usize {
+        self.head_visited_set.len()
+    }
+
+    /// Positions visited by each knot, head first, if the rope records them
+    pub fn knot_paths(&self) -> Option<&[HashSet<Point<isize>>]> {
+        self.knot_paths.as_deref()
+    }
+
+    /// Draws the current knot positions, with `H` for the head, the knot's index for the others
+    /// and `s` for the starting point, over the bounding box of every visited cell
+    pub fn render(&self) -> String {
+        let visited_it = self
+            .knot_paths
+            .iter()
+            .flatten()
+            .flatten()
+            .chain(&self.tail_visited_set)
+            .chain(&self.segments);
+        let (mut min, mut max): (Point<isize>, Point<isize>) = Default::default();
+        for p in visited_it {
+            min = Point::new(min.x.min(p.x), min.y.min(p.y));
+            max = Point::new(max.x.max(p.x), max.y.max(p.y));
+        }
+
+        // Rows go from the top down, so y is reversed
+        let mut drawing = String::new();
+        for y in (min.y..=max.y).rev() {
+            for x in min.x..=max.x {
+                let cell = Point::new(x, y);
+
+                // Knots closer to the head are drawn over the ones behind them
+                let cell_char = match self.segments.iter().position(|&s| s == cell) {
+                    Some(0) => 'H',
+                    Some(i) => char::from_digit(i as u32, 10).unwrap_or('*'),
+                    None if cell == Point::default() => 's',
+                    None => '.',
+                };
+                drawing.push(cell_char);
+            }
+            drawing.push('\n');
+        }
+
+        drawing
+    }
+}
+
+pub fn parse_str(input: &str) -> Result<Vec<(Motion, usize)>, AocError> {
+    let mut movements = Vec::new();
+    for line in input.lines() {
+        let mut fields_it = line.split_ascii_whitespace().take(2);
+
+        // Parse direction
+        let direction_str = fields_it
+            .next()
+            .ok_or(AocError::MissingField("direction"))?;
+        let direction = Motion::from_str(direction_str)?;
+
+        // Parse steps
+        let steps_str = fields_it.next().ok_or(AocError::MissingField("steps"))?;
+        let steps = steps_str.parse()?;
+
+        movements.push((direction, steps));
+    }
+
+    Ok(movements)
+}
+
+pub fn parse_input<T: AsRef<Path>>(filename: T) -> Result<Vec<(Motion, usize)>, AocError> {
+    parse_str(&read_to_string(filename)?)
+}
+
+/// Counts the positions visited by the tail of a rope with `n_segments` knots
+pub fn count_tail_positions(
+    movements: &[(Motion, usize)],
+    n_segments: usize,
+) -> Result<usize, AocError> {
+    if n_segments == 0 {
+        return Err(AocError::Parse(
+            "A rope needs at least one knot".to_string(),
+        ));
+    }
+
+    let mut rope_grid = RopeGrid::new(n_segments);
+    for movement in movements {
+        rope_grid.do_movement(movement);
+    }
+
+    Ok(rope_grid.tail_visited_set.len())
+}
+
+pub fn part1(movements: &[(Motion, usize)]) -> usize {
+    count_tail_positions(movements, 2).unwrap()
+}
+
+pub fn part2(movements: &[(Motion, usize)]) -> usize {
+    count_tail_positions(movements, 10).unwrap()
+}
+
+pub const INPUT_PATH: &str = "inputs/day09.in";
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u32 = 9;
+    const INPUT_PATH: &'static str = INPUT_PATH;
+    const PART1_LABEL: &'static str = "Amount of positions visited by the rope's tail";
+    const PART2_LABEL: &'static str = "Amount of positions visited by the rope's tail";
+
+    type Input = Vec<(Motion, usize)>;
+
+    fn parse(s: &str) -> Result<Self::Input, AocError> {
+        parse_str(s)
+    }
+
+    fn part1(movements: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part1(movements).into())
+    }
+
+    fn part2(movements: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part2(movements).into())
+    }
+}
+
+/// Solves both parts against the committed input
+pub fn run() -> Result<DayResult, AocError> {
+    solve::<Day09, _>(INPUT_PATH)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::solve_str;
+
+    const SAMPLE: &str = include_str!("../../../inputs/day09_test_p1.in");
+
+    const LARGER_SAMPLE: &str = include_str!("../../../inputs/day09_test_p2.in");
+
+    #[test]
+    fn part1_sample() {
+        let movements = parse_str(SAMPLE).unwrap();
+        assert_eq!(part1(&movements), 13);
+    }
+
+    #[test]
+    fn part2_sample() {
+        let movements = parse_str(SAMPLE).unwrap();
+        assert_eq!(part2(&movements), 1);
+
+        let movements = parse_str(LARGER_SAMPLE).unwrap();
+        assert_eq!(part2(&movements), 36);
+    }
+
+    #[test]
+    fn single_knot_follows_head_path() {
+        // Trace the head on its own, which is all a one-knot rope has
+        let movements = parse_str(SAMPLE).unwrap();
+        let mut head: Point<isize> = Point::default();
+        let mut head_path = HashSet::from([head]);
+        for (direction, steps) in &movements {
+            for _ in 0..*steps {
+                head += direction.offset();
+                head_path.insert(head);
+            }
+        }
+        assert_eq!(
+            count_tail_positions(&movements, 1).unwrap(),
+            head_path.len()
+        );
+
+        let movements = parse_str("R 3\nL 3\nU 2").unwrap();
+        assert_eq!(count_tail_positions(&movements, 1).unwrap(), 6);
+        assert!(count_tail_positions(&movements, 0).is_err());
+    }
+
+    #[test]
+    fn follow_rule_for_every_offset() {
+        let follower = Point::new(0, 0);
+
+        // Overlapping, orthogonally adjacent and diagonally adjacent knots are touching
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                assert_eq!(follow(Point::new(dx, dy), follower), follower);
+            }
+        }
+
+        // Two away in a straight line, the follower moves straight towards the leader
+        assert_eq!(follow(Point::new(2, 0), follower), Point::new(1, 0));
+        assert_eq!(follow(Point::new(-2, 0), follower), Point::new(-1, 0));
+        assert_eq!(follow(Point::new(0, 2), follower), Point::new(0, 1));
+        assert_eq!(follow(Point::new(0, -2), follower), Point::new(0, -1));
+
+        // A knight's move away, the follower steps diagonally into line with the leader
+        for (dx, dy) in [
+            (2, 1),
+            (1, 2),
+            (-1, 2),
+            (-2, 1),
+            (-2, -1),
+            (-1, -2),
+            (1, -2),
+            (2, -1),
+        ] {
+            assert_eq!(
+                follow(Point::new(dx, dy), follower),
+                Point::new(dx.signum(), dy.signum())
+            );
+        }
+
+        // Two away diagonally, which only happens with longer ropes, it moves diagonally
+        for (dx, dy) in [(2, 2), (-2, 2), (-2, -2), (2, -2)] {
+            assert_eq!(
+                follow(Point::new(dx, dy), follower),
+                Point::new(dx / 2, dy / 2)
+            );
+        }
+    }
+
+    #[test]
+    fn render_knot_paths() {
+        let mut rope_grid = RopeGrid::with_knot_paths(2);
+        for movement in &parse_str("R 4\nU 4").unwrap() {
+            rope_grid.do_movement(movement);
+        }
+        assert_eq!(rope_grid.render(), "....H\n....1\n.....\n.....\ns....\n");
+
+        let knot_paths = rope_grid.knot_paths().unwrap();
+        assert_eq!(knot_paths[0].len(), 9);
+        assert_eq!(knot_paths[1].len(), 7);
+        assert_eq!(knot_paths[1], rope_grid.tail_visited_set);
+
+        // The tail is drawn under the head when they overlap, and the bounding box grows left
+        let mut rope_grid = RopeGrid::new(2);
+        rope_grid.do_movement(&(Motion::Straight(Direction::Left), 2));
+        assert_eq!(rope_grid.render(), "H1s\n");
+        assert!(rope_grid.knot_paths().is_none());
+    }
+
+    #[test]
+    fn diagonal_moves() {
+        // The tail trails the head along the diagonal, then stays put when the head steps back
+        let movements = parse_str("UR 3\nDL 1\nR 2").unwrap();
+        assert_eq!(count_tail_positions(&movements, 2).unwrap(), 4);
+        assert_eq!(count_tail_positions(&movements, 1).unwrap(), 6);
+
+        let movements = parse_str("ul 2\nDR 4").unwrap();
+        assert_eq!(count_tail_positions(&movements, 2).unwrap(), 3);
+        assert!(parse_str("UD 1").is_err());
+        assert!(parse_str("LU 1").is_err());
+        assert!(parse_str("ULR 1").is_err());
+        assert!(parse_str("é 1").is_err());
+    }
+
+    #[test]
+    fn head_path_stats() {
+        let movements = parse_str(SAMPLE).unwrap();
+        let mut rope_grid = RopeGrid::new(2);
+        for movement in &movements {
+            rope_grid.do_movement(movement);
+        }
+
+        assert_eq!(rope_grid.head_steps(), 24);
+        assert_eq!(rope_grid.head_distinct_cells(), 21);
+        assert_eq!(
+            rope_grid.head_distinct_cells(),
+            count_tail_positions(&movements, 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn empty_input() {
+        assert_eq!(
+            solve_str::<Day09>("").unwrap(),
+            ("1".to_string(), "1".to_string())
+        );
+    }
+}
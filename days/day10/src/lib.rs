@@ -0,0 +1,517 @@
+use std::path::Path;
+use std::str::FromStr;
+
+use aoc_common::{encode_ppm, read_to_string, solve, Answer, AocError, DayResult, Solution};
+
+#[derive(Clone)]
+pub enum Instruction {
+    AddX(isize),
+    SubX(isize),
+    MulX(isize),
+    Noop,
+}
+
+impl Instruction {
+    /// Amount of cycles the instruction takes to complete
+    pub fn cycles(&self) -> usize {
+        match self {
+            Instruction::AddX(_) => 2,
+            Instruction::SubX(_) => 2,
+            Instruction::MulX(_) => 3,
+            Instruction::Noop => 1,
+        }
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut str_it = s.split_ascii_whitespace().take(2);
+        let instruction_str = str_it.next().ok_or(AocError::MissingField("instruction"))?;
+        let num_str_opt = str_it.next();
+
+        match instruction_str {
+            "addx" => {
+                let num_str = num_str_opt.ok_or(AocError::MissingField("addx operand"))?;
+                let num = num_str.parse::<isize>()?;
+                Ok(Self::AddX(num))
+            }
+            "subx" => {
+                let num_str = num_str_opt.ok_or(AocError::MissingField("subx operand"))?;
+                let num = num_str.parse::<isize>()?;
+                Ok(Self::SubX(num))
+            }
+            "mulx" => {
+                let num_str = num_str_opt.ok_or(AocError::MissingField("mulx operand"))?;
+                let num = num_str.parse::<isize>()?;
+                Ok(Self::MulX(num))
+            }
+            "noop" => Ok(Self::Noop),
+            other => Err(AocError::Parse(format!(
+                "Invalid instruction string '{}'",
+                other
+            ))),
+        }
+    }
+}
+
+/// Width of a letter drawn on the CRT, plus the blank column that separates it from the next
+const GLYPH_WIDTH: usize = 5;
+
+/// The letters AoC draws on the CRT, four pixels wide and six tall
+const FONT: [(char, [&str; 6]); 17] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+#[derive(Clone)]
+pub struct Cpu {
+    register_x: isize,
+
+    instructions: Vec<Instruction>,
+    program_counter: usize,
+
+    cycles_left: usize,
+    current_cycle: usize,
+
+    width: usize,
+    height: usize,
+    crt: Vec<Vec<char>>,
+}
+
+impl Cpu {
+    /// Creates a CPU wired to a `width` by `height` CRT, which is 40 by 6 for the puzzle. Fails if
+    /// the CRT has no pixels, since every cycle draws to one
+    pub fn new(
+        instructions: Vec<Instruction>,
+        width: usize,
+        height: usize,
+    ) -> Result<Self, AocError> {
+        if width == 0 || height == 0 {
+            return Err(AocError::Parse(format!(
+                "The CRT needs at least one pixel, got {}x{}",
+                width, height
+            )));
+        }
+
+        let crt = vec![vec!['.'; width]; height];
+
+        let mut cpu = Cpu {
+            register_x: 1,
+            instructions,
+            program_counter: 0,
+            cycles_left: 0,
+            current_cycle: 0,
+            width,
+            height,
+            crt,
+        };
+        cpu.load_next_instruction();
+
+        Ok(cpu)
+    }
+
+    fn load_next_instruction(&mut self) {
+        // An empty program has nothing to load and never runs a cycle
+        if let Some(instruction) = self.instructions.get(self.program_counter) {
+            self.cycles_left += instruction.cycles();
+        }
+    }
+
+    fn finish_instruction(&mut self) -> Result<bool, AocError> {
+        let register_x = match &self.instructions[self.program_counter] {
+            Instruction::AddX(num) => self.register_x.checked_add(*num),
+            Instruction::SubX(num) => self.register_x.checked_sub(*num),
+            Instruction::MulX(num) => self.register_x.checked_mul(*num),
+            Instruction::Noop => Some(self.register_x),
+        };
+        self.register_x = register_x.ok_or_else(|| {
+            AocError::Overflow(format!(
+                "Instruction {} overflows the X register",
+                self.program_counter + 1
+            ))
+        })?;
+
+        self.program_counter += 1;
+        Ok(self.program_counter < self.instructions.len())
+    }
+
+    /// Advances the program by one cycle, returning false once it has no cycles left to run.
+    /// While it returns true, the accessors hold the state during the cycle that just ran. Fails
+    /// if an instruction overflows the X register
+    pub fn run_cycle(&mut self) -> Result<bool, AocError> {
+        // The program has already finished
+        if self.program_counter >= self.instructions.len() {
+            return Ok(false);
+        }
+
+        if self.cycles_left == 0 {
+            if !self.finish_instruction()? {
+                return Ok(false);
+            }
+            self.load_next_instruction();
+        }
+
+        self.current_cycle += 1;
+
+        // Cycles past the last row have nowhere to draw
+        let crt_pixel_x = ((self.current_cycle - 1) % self.width) as isize;
+        let crt_pixel_y = (self.current_cycle - 1) / self.width;
+        let sprite_visible = crt_pixel_x.abs_diff(self.register_x) <= 1;
+        if sprite_visible && crt_pixel_y < self.height {
+            self.crt[crt_pixel_y][crt_pixel_x as usize] = '#';
+        }
+
+        self.cycles_left -= 1;
+
+        Ok(true)
+    }
+
+    /// Number of the cycle that last ran, starting at 1
+    pub fn current_cycle(&self) -> usize {
+        self.current_cycle
+    }
+
+    pub fn register_x(&self) -> isize {
+        self.register_x
+    }
+
+    /// Signal strength during the cycle that last ran, failing if it doesn't fit an `isize`
+    pub fn signal_strength(&self) -> Result<isize, AocError> {
+        signal_strength(self.register_x, self.current_cycle)
+    }
+
+    /// Runs the rest of the program, returning the value of the X register during each cycle,
+    /// so index 0 holds the first cycle for a fresh CPU
+    pub fn run_to_completion(&mut self) -> Result<Vec<isize>, AocError> {
+        let mut register_x_history = Vec::new();
+        while self.run_cycle()? {
+            register_x_history.push(self.register_x);
+        }
+
+        Ok(register_x_history)
+    }
+
+    /// Reads the letters drawn on the CRT, with `?` for any cell that isn't a known letter.
+    /// Only the puzzle's six rows tall screen holds letters, otherwise nothing is read
+    pub fn ocr(&self) -> String {
+        if self.height != FONT[0].1.len() {
+            return String::new();
+        }
+
+        // Letters are left aligned, with the last one's blank column possibly cut off
+        (0..(self.width + 1) / GLYPH_WIDTH)
+            .map(|letter_idx| {
+                let x = letter_idx * GLYPH_WIDTH;
+                FONT.iter()
+                    .find(|(_, glyph)| {
+                        glyph.iter().zip(&self.crt).all(|(glyph_row, crt_row)| {
+                            glyph_row.chars().eq(crt_row[x..x + 4].iter().copied())
+                        })
+                    })
+                    .map_or('?', |&(letter, _)| letter)
+            })
+            .collect()
+    }
+
+    /// Encodes the CRT as a binary PPM image, with lit pixels in white, dark ones in black and
+    /// each pixel drawn as a `scale` by `scale` square
+    pub fn to_ppm(&self, scale: usize) -> Vec<u8> {
+        encode_ppm(self.width, self.height, scale, |row, col| {
+            if self.crt[row][col] == '#' {
+                [255; 3]
+            } else {
+                [0; 3]
+            }
+        })
+    }
+
+    fn get_crt(&self) -> String {
+        self.crt
+            .iter()
+            .map(String::from_iter)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+fn signal_strength(register_x: isize, cycle: usize) -> Result<isize, AocError> {
+    isize::try_from(cycle)
+        .ok()
+        .and_then(|cycle| register_x.checked_mul(cycle))
+        .ok_or_else(|| AocError::Overflow(format!("Signal strength of cycle {} overflows", cycle)))
+}
+
+pub fn parse_str(input: &str) -> Result<Vec<Instruction>, AocError> {
+    let mut instructions = Vec::new();
+    for line in input.lines() {
+        let instruction = Instruction::from_str(line)?;
+        instructions.push(instruction);
+    }
+
+    Ok(instructions)
+}
+
+pub fn parse_input<T: AsRef<Path>>(filename: T) -> Result<Vec<Instruction>, AocError> {
+    parse_str(&read_to_string(filename)?)
+}
+
+pub fn part1(cpu: &mut Cpu) -> Result<isize, AocError> {
+    // Sample cycles 20, 60, 100, ...
+    let register_x_history = cpu.run_to_completion()?;
+    (20..=register_x_history.len())
+        .step_by(40)
+        .try_fold(0isize, |sum, cycle| {
+            let strength = signal_strength(register_x_history[cycle - 1], cycle)?;
+            sum.checked_add(strength)
+                .ok_or_else(|| AocError::Overflow("Sum of signal strengths overflows".to_string()))
+        })
+}
+
+pub fn part2(cpu: &mut Cpu) -> Result<String, AocError> {
+    // Finish drawing the screen, in case part 1 didn't run the program already
+    while cpu.run_cycle()? {}
+
+    Ok(cpu.get_crt())
+}
+
+pub const INPUT_PATH: &str = "inputs/day10.in";
+
+/// Dimensions of the puzzle's CRT
+pub const CRT_WIDTH: usize = 40;
+pub const CRT_HEIGHT: usize = 6;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    const INPUT_PATH: &'static str = INPUT_PATH;
+    const PART1_LABEL: &'static str = "Sum of signal strenghts";
+    const PART2_LABEL: &'static str = "CRT screen";
+
+    type Input = Cpu;
+
+    fn parse(s: &str) -> Result<Self::Input, AocError> {
+        Cpu::new(parse_str(s)?, CRT_WIDTH, CRT_HEIGHT)
+    }
+
+    fn part1(cpu: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part1(&mut cpu.clone())?.into())
+    }
+
+    fn part2(cpu: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part2(&mut cpu.clone())?.into())
+    }
+}
+
+/// Solves both parts against the committed input
+pub fn run() -> Result<DayResult, AocError> {
+    solve::<Day10, _>(INPUT_PATH)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::solve_str;
+
+    const SAMPLE: &str = include_str!("../../../inputs/day10_test.in");
+
+    #[test]
+    fn part1_sample() {
+        let mut cpu = Cpu::new(parse_str(SAMPLE).unwrap(), CRT_WIDTH, CRT_HEIGHT).unwrap();
+        assert_eq!(part1(&mut cpu).unwrap(), 13140);
+    }
+
+    #[test]
+    fn part2_sample() {
+        let mut cpu = Cpu::new(parse_str(SAMPLE).unwrap(), CRT_WIDTH, CRT_HEIGHT).unwrap();
+        assert_eq!(
+            part2(&mut cpu).unwrap(),
+            "##..##..##..##..##..##..##..##..##..##..\n\
+             ###...###...###...###...###...###...###.\n\
+             ####....####....####....####....####....\n\
+             #####.....#####.....#####.....#####.....\n\
+             ######......######......######......####\n\
+             #######.......#######.......#######....."
+        );
+    }
+
+    #[test]
+    fn parse_invalid_instructions() {
+        assert!(matches!(
+            Instruction::from_str(""),
+            Err(AocError::MissingField("instruction"))
+        ));
+        assert!(matches!(
+            Instruction::from_str("addx"),
+            Err(AocError::MissingField("addx operand"))
+        ));
+        assert!(matches!(
+            Instruction::from_str("addx y"),
+            Err(AocError::Parse(_))
+        ));
+        assert!(matches!(
+            Instruction::from_str("jmp 3"),
+            Err(AocError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn other_crt_sizes() {
+        // The sample's sprite moves across 40 columns, so the second half of each original
+        // row lands where the beam never reaches it
+        let mut cpu = Cpu::new(parse_str(SAMPLE).unwrap(), 20, 3).unwrap();
+        assert_eq!(
+            part2(&mut cpu).unwrap(),
+            "##..##..##..##..##..\n\
+             ....................\n\
+             ###...###...###...##"
+        );
+
+        // Signal strengths don't depend on the screen
+        let mut cpu = Cpu::new(parse_str(SAMPLE).unwrap(), 7, 1).unwrap();
+        assert_eq!(part1(&mut cpu).unwrap(), 13140);
+    }
+
+    #[test]
+    fn crt_letters() {
+        let input = include_str!("../../../inputs/day10.in");
+        let mut cpu = Cpu::new(parse_str(input).unwrap(), CRT_WIDTH, CRT_HEIGHT).unwrap();
+        part2(&mut cpu).unwrap();
+        assert_eq!(cpu.ocr(), "PZULBAUA");
+
+        // The sample draws stripes, not letters
+        let mut cpu = Cpu::new(parse_str(SAMPLE).unwrap(), CRT_WIDTH, CRT_HEIGHT).unwrap();
+        part2(&mut cpu).unwrap();
+        assert_eq!(cpu.ocr(), "????????");
+    }
+
+    #[test]
+    fn register_x_history() {
+        let instructions = parse_str(SAMPLE).unwrap();
+        let total_cycles: usize = instructions.iter().map(Instruction::cycles).sum();
+
+        let mut cpu = Cpu::new(instructions, CRT_WIDTH, CRT_HEIGHT).unwrap();
+        let register_x_history = cpu.run_to_completion().unwrap();
+        assert_eq!(register_x_history.len(), total_cycles);
+        assert_eq!(register_x_history.len(), 240);
+        assert_eq!(register_x_history[19], 21);
+        assert_eq!(register_x_history[219], 18);
+
+        // Nothing is left to run afterwards
+        assert!(cpu.run_to_completion().unwrap().is_empty());
+    }
+
+    #[test]
+    fn subx_and_mulx() {
+        assert!(matches!(
+            Instruction::from_str("subx 3"),
+            Ok(Instruction::SubX(3))
+        ));
+        assert!(matches!(
+            Instruction::from_str("mulx -2"),
+            Ok(Instruction::MulX(-2))
+        ));
+        assert!(matches!(
+            Instruction::from_str("mulx"),
+            Err(AocError::MissingField("mulx operand"))
+        ));
+
+        // X starts at 1, so it's 1 - 3 = -2 after the subx, and -2 * -4 = 8 after the mulx
+        let mut cpu = Cpu::new(
+            parse_str("subx 3\nmulx -4\nnoop").unwrap(),
+            CRT_WIDTH,
+            CRT_HEIGHT,
+        )
+        .unwrap();
+        assert_eq!(cpu.run_to_completion().unwrap(), vec![1, 1, -2, -2, -2, 8]);
+    }
+
+    #[test]
+    fn external_sampler() {
+        let mut cpu = Cpu::new(parse_str(SAMPLE).unwrap(), CRT_WIDTH, CRT_HEIGHT).unwrap();
+        let mut signal_strength_sum = 0;
+        while cpu.run_cycle().unwrap() {
+            if cpu.current_cycle() % 40 == 20 {
+                signal_strength_sum += cpu.signal_strength().unwrap();
+            }
+            if cpu.current_cycle() == 60 {
+                assert_eq!(cpu.register_x(), 19);
+            }
+        }
+
+        assert_eq!(signal_strength_sum, 13140);
+        assert_eq!(cpu.current_cycle(), 240);
+    }
+
+    #[test]
+    fn ppm_image() {
+        let mut cpu = Cpu::new(parse_str("addx 5\nnoop\nnoop").unwrap(), 2, 2).unwrap();
+        while cpu.run_cycle().unwrap() {}
+
+        // The sprite covers the first row, then X moves away from the second
+        let ppm = cpu.to_ppm(3);
+        let header = b"P6\n6 6\n255\n";
+        assert_eq!(&ppm[..header.len()], header);
+        assert_eq!(ppm.len(), header.len() + 6 * 6 * 3);
+        assert!(ppm[header.len()..header.len() + 6 * 3]
+            .iter()
+            .all(|&c| c == 255));
+        assert!(ppm[header.len() + 3 * 6 * 3..].iter().all(|&c| c == 0));
+    }
+
+    #[test]
+    fn empty_input() {
+        let (part1, part2) = solve_str::<Day10>("").unwrap();
+        assert_eq!(part1, "0");
+        assert_eq!(part2, vec![".".repeat(CRT_WIDTH); CRT_HEIGHT].join("\n"));
+    }
+
+    #[test]
+    fn crt_without_pixels() {
+        for (width, height) in [(0, CRT_HEIGHT), (CRT_WIDTH, 0), (0, 0)] {
+            assert!(matches!(
+                Cpu::new(parse_str(SAMPLE).unwrap(), width, height),
+                Err(AocError::Parse(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn register_overflow() {
+        for program in [
+            "addx 9223372036854775806\naddx 1",
+            "subx 3\nsubx 9223372036854775807",
+            "mulx 9223372036854775807\nmulx 2",
+        ] {
+            let mut cpu = Cpu::new(parse_str(program).unwrap(), CRT_WIDTH, CRT_HEIGHT).unwrap();
+            assert!(
+                matches!(cpu.run_to_completion(), Err(AocError::Overflow(_))),
+                "{}",
+                program
+            );
+        }
+
+        // X itself fits, but its signal strength doesn't
+        let program = format!("addx 4611686018427387903\n{}", "noop\n".repeat(20));
+        let cpu = Day10::parse(&program).unwrap();
+        assert!(matches!(Day10::part1(&cpu), Err(AocError::Overflow(_))));
+        assert!(Day10::part2(&cpu).is_ok());
+    }
+}
@@ -0,0 +1,50 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day11::{parse_str, parse_str_regex, INPUT_PATH};
+use regex::Regex;
+
+/// How many times each benchmark iteration parses the input
+const PARSES: usize = 100;
+
+/// Compiles the regexes the way the previous parser did on every call
+fn compile_regexes() -> [Regex; 5] {
+    [
+        r"Starting\s+items:\s*((?:\d+(?:,\s*)?)+)",
+        r"Operation:\s*new\s*=\s*(.+)",
+        r"Test:\s*divisible\s+by\s+(\d+)",
+        r"If\s+true:\s*throw\s+to\s+monkey\s+(\d+)",
+        r"If\s+false:\s*throw\s+to\s+monkey\s+(\d+)",
+    ]
+    .map(|re| Regex::new(re).unwrap())
+}
+
+fn bench_parse(c: &mut Criterion) {
+    // Benches run from the crate directory
+    let input = std::fs::read_to_string(format!("../../{}", INPUT_PATH)).unwrap();
+    let input = input.trim();
+
+    let mut group = c.benchmark_group("parse");
+    group.bench_function("prefixes", |b| b.iter(|| parse_str(black_box(input))));
+    group.bench_function("regex", |b| b.iter(|| parse_str_regex(black_box(input))));
+    group.finish();
+
+    let mut group = c.benchmark_group("parse_100_times");
+    group.bench_function("cached_regexes", |b| {
+        b.iter(|| {
+            for _ in 0..PARSES {
+                black_box(parse_str_regex(black_box(input)).unwrap());
+            }
+        })
+    });
+    group.bench_function("compiled_per_parse", |b| {
+        b.iter(|| {
+            for _ in 0..PARSES {
+                black_box(compile_regexes());
+                black_box(parse_str_regex(black_box(input)).unwrap());
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_parse);
+criterion_main!(benches);
@@ -0,0 +1,274 @@
+use std::ops::{Add, Mul};
+use std::str::FromStr;
+
+use aoc_common::AocError;
+
+/// Arithmetic expression over the old worry level, like `old * old + 3` or `(old + 2) * 5`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    Old,
+    Num(usize),
+    Add(Box<Expr>, Box<Expr>),
+    Mul(Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    /// Computes the new worry level from the old one
+    pub fn eval<W>(&self, old: &W) -> W
+    where
+        W: Clone + From<usize> + Add<Output = W> + Mul<Output = W>,
+    {
+        match self {
+            Expr::Old => old.clone(),
+            Expr::Num(n) => W::from(*n),
+            Expr::Add(lhs, rhs) => lhs.eval(old) + rhs.eval(old),
+            Expr::Mul(lhs, rhs) => lhs.eval(old) * rhs.eval(old),
+        }
+    }
+
+    /// Same as `eval` on `usize` worry levels, but `None` if any step overflows
+    pub fn checked_eval(&self, old: usize) -> Option<usize> {
+        match self {
+            Expr::Old => Some(old),
+            Expr::Num(n) => Some(*n),
+            Expr::Add(lhs, rhs) => lhs.checked_eval(old)?.checked_add(rhs.checked_eval(old)?),
+            Expr::Mul(lhs, rhs) => lhs.checked_eval(old)?.checked_mul(rhs.checked_eval(old)?),
+        }
+    }
+}
+
+/// Most parentheses an expression can nest, so deep inputs can't overflow the parser's stack
+const MAX_NESTING: usize = 256;
+
+/// Most tokens in an expression. Operator chains build trees as deep as they are long, which
+/// evaluating and dropping them walk recursively
+const MAX_TOKENS: usize = 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token {
+    Old,
+    Num(usize),
+    Plus,
+    Star,
+    LeftParen,
+    RightParen,
+}
+
+fn tokenize(s: &str) -> Result<Vec<Token>, AocError> {
+    let mut tokens = Vec::new();
+    let mut chars_it = s.char_indices().peekable();
+    while let Some((start, c)) = chars_it.next() {
+        let token = match c {
+            '+' => Token::Plus,
+            '*' => Token::Star,
+            '(' => Token::LeftParen,
+            ')' => Token::RightParen,
+            c if c.is_whitespace() => continue,
+            c if c.is_ascii_alphanumeric() => {
+                // Take the whole word or number
+                let mut end = start + c.len_utf8();
+                while let Some(&(i, c)) = chars_it.peek() {
+                    if !c.is_ascii_alphanumeric() {
+                        break;
+                    }
+                    end = i + c.len_utf8();
+                    chars_it.next();
+                }
+
+                match &s[start..end] {
+                    "old" => Token::Old,
+                    word => Token::Num(word.parse().map_err(|_| {
+                        AocError::Parse(format!("Unexpected '{}' in expression '{}'", word, s))
+                    })?),
+                }
+            }
+            other => {
+                return Err(AocError::Parse(format!(
+                    "Unexpected '{}' in expression '{}'",
+                    other, s
+                )))
+            }
+        };
+        tokens.push(token);
+        if tokens.len() > MAX_TOKENS {
+            return Err(AocError::Parse(format!(
+                "Expression '{}' has more than {} tokens",
+                s, MAX_TOKENS
+            )));
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Recursive descent parser, where `*` binds tighter than `+`:
+///
+/// ```text
+/// expr   = term ('+' term)*
+/// term   = factor ('*' factor)*
+/// factor = 'old' | number | '(' expr ')'
+/// ```
+struct Parser<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+    /// Parentheses opened around the current position
+    depth: usize,
+    source: &'a str,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<Token> {
+        self.tokens.get(self.pos).copied()
+    }
+
+    fn error(&self, expected: &str) -> AocError {
+        AocError::Parse(format!(
+            "Expected {} at token {} of expression '{}'",
+            expected,
+            self.pos + 1,
+            self.source
+        ))
+    }
+
+    fn expr(&mut self) -> Result<Expr, AocError> {
+        let mut lhs = self.term()?;
+        while self.peek() == Some(Token::Plus) {
+            self.pos += 1;
+            lhs = Expr::Add(Box::new(lhs), Box::new(self.term()?));
+        }
+
+        Ok(lhs)
+    }
+
+    fn term(&mut self) -> Result<Expr, AocError> {
+        let mut lhs = self.factor()?;
+        while self.peek() == Some(Token::Star) {
+            self.pos += 1;
+            lhs = Expr::Mul(Box::new(lhs), Box::new(self.factor()?));
+        }
+
+        Ok(lhs)
+    }
+
+    fn factor(&mut self) -> Result<Expr, AocError> {
+        let token = self.peek().ok_or_else(|| self.error("a value"))?;
+        self.pos += 1;
+
+        match token {
+            Token::Old => Ok(Expr::Old),
+            Token::Num(n) => Ok(Expr::Num(n)),
+            Token::LeftParen => {
+                if self.depth == MAX_NESTING {
+                    return Err(AocError::Parse(format!(
+                        "Expression '{}' nests more than {} parentheses",
+                        self.source, MAX_NESTING
+                    )));
+                }
+                self.depth += 1;
+                let inner = self.expr()?;
+                if self.peek() != Some(Token::RightParen) {
+                    return Err(self.error("')'"));
+                }
+                self.pos += 1;
+                self.depth -= 1;
+
+                Ok(inner)
+            }
+            _ => {
+                self.pos -= 1;
+                Err(self.error("a value"))
+            }
+        }
+    }
+}
+
+impl FromStr for Expr {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tokens = tokenize(s)?;
+        let mut parser = Parser {
+            tokens: &tokens,
+            pos: 0,
+            depth: 0,
+            source: s,
+        };
+
+        let expr = parser.expr()?;
+        if parser.pos < tokens.len() {
+            return Err(parser.error("the end"));
+        }
+
+        Ok(expr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval(s: &str, old: usize) -> usize {
+        s.parse::<Expr>().unwrap().eval(&old)
+    }
+
+    #[test]
+    fn puzzle_operations() {
+        assert_eq!(eval("old * 19", 2), 38);
+        assert_eq!(eval("old + 6", 2), 8);
+        assert_eq!(eval("old * old", 7), 49);
+    }
+
+    #[test]
+    fn checked_operations() {
+        let square: Expr = "old * old + 1".parse().unwrap();
+        assert_eq!(square.checked_eval(7), Some(50));
+        assert_eq!(square.checked_eval(1 << 32), None);
+        assert_eq!(
+            "old + 1".parse::<Expr>().unwrap().checked_eval(usize::MAX),
+            None
+        );
+    }
+
+    #[test]
+    fn compound_expressions() {
+        assert_eq!(eval("old * old + 3", 4), 19);
+        assert_eq!(eval("3 + old * old", 4), 19);
+        assert_eq!(eval("(old + 2) * 5", 4), 30);
+        assert_eq!(eval("((old))*(old+1)+2*3", 4), 26);
+        assert_eq!(
+            "old + 1 * 2".parse::<Expr>().unwrap(),
+            Expr::Add(
+                Box::new(Expr::Old),
+                Box::new(Expr::Mul(Box::new(Expr::Num(1)), Box::new(Expr::Num(2))))
+            )
+        );
+    }
+
+    #[test]
+    fn invalid_expressions() {
+        for s in ["", "old +", "(old + 2", "old 2", "old - 2", "new * 2", ")"] {
+            assert!(
+                matches!(s.parse::<Expr>(), Err(AocError::Parse(_))),
+                "{}",
+                s
+            );
+        }
+    }
+
+    #[test]
+    fn deep_expressions() {
+        let nested = |depth| format!("{}old{}", "(".repeat(depth), ")".repeat(depth));
+        assert_eq!(eval(&nested(MAX_NESTING), 3), 3);
+        assert!(matches!(
+            nested(MAX_NESTING + 1).parse::<Expr>(),
+            Err(AocError::Parse(_))
+        ));
+        assert!(matches!(
+            nested(200_000).parse::<Expr>(),
+            Err(AocError::Parse(_))
+        ));
+
+        let chain = vec!["old"; 100_000].join(" + ");
+        assert!(matches!(chain.parse::<Expr>(), Err(AocError::Parse(_))));
+    }
+}
@@ -0,0 +1,735 @@
+use std::num::NonZeroUsize;
+use std::path::Path;
+
+use aoc_common::{
+    read_to_string_trimmed, solve, split_records, Answer, AocError, DayResult, Solution,
+};
+use num::{BigUint, Integer, Zero};
+
+mod expr;
+#[cfg(feature = "regex-parser")]
+mod regex_parser;
+
+pub use expr::Expr;
+#[cfg(feature = "regex-parser")]
+pub use regex_parser::parse_str_regex;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThrowCheck {
+    modulo: usize,
+    if_true_monkey: usize,
+    if_false_monkey: usize,
+}
+
+impl ThrowCheck {
+    fn new(modulo: usize, if_true_monkey: usize, if_false_monkey: usize) -> Self {
+        ThrowCheck {
+            modulo,
+            if_true_monkey,
+            if_false_monkey,
+        }
+    }
+}
+
+/// A monkey holding items of worry level type `W`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Monkey<W = usize> {
+    items: Vec<W>,
+    inspect_op: Expr,
+    throw_check: ThrowCheck,
+    inspect_count: usize,
+}
+
+impl Monkey {
+    fn new(items: Vec<usize>, inspect_op: Expr, throw_check: ThrowCheck) -> Self {
+        Monkey {
+            items,
+            inspect_op,
+            throw_check,
+            inspect_count: 0,
+        }
+    }
+}
+
+/// Every throw made during a round, as `(worry level, monkey thrown to)` pairs listed for each
+/// monkey in the order it inspected the items
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RoundLog {
+    pub throws: Vec<Vec<(usize, usize)>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MonkeyPack {
+    monkeys: Vec<Monkey>,
+    global_lcm: usize,
+}
+
+impl MonkeyPack {
+    fn new(monkeys: Vec<Monkey>) -> Result<Self, AocError> {
+        // Every item has to land on another monkey that exists, or the rounds would panic or
+        // never end
+        for (monkey_index, monkey) in monkeys.iter().enumerate() {
+            let check = &monkey.throw_check;
+            for target in [check.if_true_monkey, check.if_false_monkey] {
+                if target >= monkeys.len() || target == monkey_index {
+                    return Err(AocError::Parse(format!(
+                        "Monkey {} throws to monkey {}, but it can only throw to another of the {} monkeys",
+                        monkey_index,
+                        target,
+                        monkeys.len()
+                    )));
+                }
+            }
+        }
+
+        let global_lcm = monkeys
+            .iter()
+            .map(|m| m.throw_check.modulo)
+            .try_fold(1usize, |acc, m| (acc / acc.gcd(&m)).checked_mul(m))
+            .ok_or_else(|| {
+                AocError::Parse("The LCM of the monkeys' tests is too large".to_string())
+            })?;
+
+        Ok(MonkeyPack {
+            monkeys,
+            global_lcm,
+        })
+    }
+
+    fn run_one_round(&mut self, divide_by: Option<NonZeroUsize>) -> Result<(), AocError> {
+        self.run_round_with_log(divide_by, None)
+    }
+
+    /// Runs a round like `run_one_round`, also recording every throw made during it
+    pub fn run_one_round_logged(
+        &mut self,
+        divide_by: Option<NonZeroUsize>,
+    ) -> Result<RoundLog, AocError> {
+        let mut round_log = RoundLog {
+            throws: vec![Vec::new(); self.monkeys.len()],
+        };
+        self.run_round_with_log(divide_by, Some(&mut round_log))?;
+
+        Ok(round_log)
+    }
+
+    /// Fails if a worry level doesn't fit a `usize`, which can happen when they're divided, since
+    /// they're only kept small by the LCM reduction otherwise
+    fn run_round_with_log(
+        &mut self,
+        divide_by: Option<NonZeroUsize>,
+        mut log: Option<&mut RoundLog>,
+    ) -> Result<(), AocError> {
+        for monkey_index in 0..self.monkeys.len() {
+            let modulo = self.monkeys[monkey_index].throw_check.modulo;
+
+            while !self.monkeys[monkey_index].items.is_empty() {
+                // Get next item
+                let mut worry_lvl = self.monkeys[monkey_index].items.remove(0);
+
+                // Do inspect operation to increase worry level
+                worry_lvl = self.monkeys[monkey_index]
+                    .inspect_op
+                    .checked_eval(worry_lvl)
+                    .ok_or_else(|| {
+                        AocError::Overflow(format!(
+                            "Worry level of an item inspected by monkey {} overflows",
+                            monkey_index
+                        ))
+                    })?;
+
+                // Increment inspeect counter
+                self.monkeys[monkey_index].inspect_count += 1;
+
+                if let Some(divisor) = divide_by {
+                    // Monkey gets bored, divide worry level
+                    worry_lvl /= divisor.get();
+                }
+
+                // Check which monkey to throw to
+                let monkey_thrown_to = if worry_lvl.is_multiple_of(modulo) {
+                    self.monkeys[monkey_index].throw_check.if_true_monkey
+                } else {
+                    self.monkeys[monkey_index].throw_check.if_false_monkey
+                };
+
+                if let Some(log) = log.as_deref_mut() {
+                    log.throws[monkey_index].push((worry_lvl, monkey_thrown_to));
+                }
+
+                // Reducing modulo the tests' LCM keeps every test result the same, but only while
+                // there's no division, since dividing a reduced level gives a different result
+                if divide_by.is_none() {
+                    worry_lvl %= self.global_lcm;
+                }
+
+                // Throw item
+                self.monkeys[monkey_thrown_to].items.push(worry_lvl);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Runs several rounds in a row, dividing worry levels by `divide_by` after inspections if given.
+    /// Worry levels are only reduced modulo the tests' LCM when there's no divisor, so with one
+    /// they grow unbounded and long runs can fail with an overflow
+    pub fn run_rounds(
+        &mut self,
+        rounds: usize,
+        divide_by: Option<NonZeroUsize>,
+    ) -> Result<(), AocError> {
+        self.run_rounds_with_progress(rounds, divide_by, |_| {})
+    }
+
+    /// Same as `run_rounds`, calling `on_round` with the amount of rounds done after each one
+    pub fn run_rounds_with_progress<F>(
+        &mut self,
+        rounds: usize,
+        divide_by: Option<NonZeroUsize>,
+        mut on_round: F,
+    ) -> Result<(), AocError>
+    where
+        F: FnMut(usize),
+    {
+        for round in 1..=rounds {
+            self.run_one_round(divide_by)?;
+            on_round(round);
+        }
+
+        Ok(())
+    }
+
+    /// Product of the inspection counts of the two most active monkeys
+    pub fn monkey_business(&self) -> Result<usize, AocError> {
+        self.monkey_business_top_k(2)
+    }
+
+    /// Product of the inspection counts of the `k` most active monkeys. With fewer than `k`
+    /// monkeys in the pack, all of them are counted. Fails if the product doesn't fit a `usize`
+    pub fn monkey_business_top_k(&self, k: usize) -> Result<usize, AocError> {
+        let mut inspect_counts = self.inspect_counts();
+        inspect_counts.sort_unstable_by(|x, y| y.cmp(x));
+        inspect_counts[..k.min(inspect_counts.len())]
+            .iter()
+            .try_fold(1usize, |acc, &count| acc.checked_mul(count))
+            .ok_or_else(|| {
+                AocError::Overflow(format!(
+                    "Monkey business of the top {} inspection counts overflows",
+                    k
+                ))
+            })
+    }
+
+    /// Amount of items each monkey inspected so far
+    pub fn inspect_counts(&self) -> Vec<usize> {
+        self.monkeys.iter().map(|m| m.inspect_count).collect()
+    }
+
+    /// Amount of items each monkey is currently holding
+    pub fn items_per_monkey(&self) -> Vec<usize> {
+        self.monkeys.iter().map(|m| m.items.len()).collect()
+    }
+
+    /// Index of the monkey holding the most items, the first one if several hold as many
+    pub fn most_loaded_monkey(&self) -> usize {
+        self.items_per_monkey()
+            .into_iter()
+            .enumerate()
+            .max_by_key(|&(i, n_items)| (n_items, std::cmp::Reverse(i)))
+            .map_or(0, |(i, _)| i)
+    }
+}
+
+/// Same monkeys as `MonkeyPack`, but with unbounded worry levels that are never reduced, neither
+/// by dividing them nor by the LCM of the tests. It shows why part 2 needs that trick, since the
+/// numbers quickly grow too large to keep up with
+#[derive(Debug, Clone)]
+pub struct BigMonkeyPack {
+    monkeys: Vec<Monkey<BigUint>>,
+}
+
+impl BigMonkeyPack {
+    pub fn run_one_round(&mut self) {
+        for monkey_index in 0..self.monkeys.len() {
+            let items = std::mem::take(&mut self.monkeys[monkey_index].items);
+            for worry_lvl in items {
+                let monkey = &mut self.monkeys[monkey_index];
+
+                // Do inspect operation to increase worry level
+                let worry_lvl = monkey.inspect_op.eval(&worry_lvl);
+                monkey.inspect_count += 1;
+
+                // Check which monkey to throw to
+                let monkey_thrown_to = if (&worry_lvl % monkey.throw_check.modulo).is_zero() {
+                    monkey.throw_check.if_true_monkey
+                } else {
+                    monkey.throw_check.if_false_monkey
+                };
+                self.monkeys[monkey_thrown_to].items.push(worry_lvl);
+            }
+        }
+    }
+
+    pub fn run_rounds(&mut self, rounds: usize) {
+        for _ in 0..rounds {
+            self.run_one_round();
+        }
+    }
+
+    /// Amount of items each monkey inspected so far
+    pub fn inspect_counts(&self) -> Vec<usize> {
+        self.monkeys.iter().map(|m| m.inspect_count).collect()
+    }
+}
+
+impl From<&MonkeyPack> for BigMonkeyPack {
+    fn from(monkey_pack: &MonkeyPack) -> Self {
+        let monkeys = monkey_pack
+            .monkeys
+            .iter()
+            .map(|m| Monkey {
+                items: m.items.iter().map(|&item| BigUint::from(item)).collect(),
+                inspect_op: m.inspect_op.clone(),
+                throw_check: m.throw_check.clone(),
+                inspect_count: m.inspect_count,
+            })
+            .collect();
+
+        BigMonkeyPack { monkeys }
+    }
+}
+
+/// Amount of rounds each part runs for
+pub const PART1_ROUNDS: usize = 20;
+pub const PART2_ROUNDS: usize = 10000;
+
+/// How much worry levels are divided by after each inspection in part 1
+pub const PART1_WORRY_DIVISOR: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+
+/// Monkey business after running `rounds` rounds on the given pack
+pub fn monkey_business_after(
+    mut monkey_pack: MonkeyPack,
+    rounds: usize,
+    divide_by: Option<NonZeroUsize>,
+) -> Result<usize, AocError> {
+    monkey_pack.run_rounds(rounds, divide_by)?;
+    monkey_pack.monkey_business()
+}
+
+pub fn part1(monkey_pack: MonkeyPack) -> Result<usize, AocError> {
+    monkey_business_after(monkey_pack, PART1_ROUNDS, Some(PART1_WORRY_DIVISOR))
+}
+
+pub fn part2(monkey_pack: MonkeyPack) -> Result<usize, AocError> {
+    monkey_business_after(monkey_pack, PART2_ROUNDS, None)
+}
+
+/// Lines describing each monkey, after the one with its number
+const MONKEY_FIELDS: usize = 5;
+
+/// Text of each field of a monkey, as found in its block
+struct MonkeyFields<'a> {
+    items: &'a str,
+    operation: &'a str,
+    test: &'a str,
+    if_true: &'a str,
+    if_false: &'a str,
+}
+
+impl MonkeyFields<'_> {
+    fn into_monkey(self) -> Result<Monkey, AocError> {
+        let items = self
+            .items
+            .split(',')
+            .map(|n| n.trim().parse())
+            .collect::<Result<_, _>>()?;
+        let operation: Expr = self.operation.parse()?;
+
+        let test: usize = self.test.parse()?;
+        if test == 0 {
+            return Err(AocError::Parse(
+                "Monkeys can't test for divisibility by 0".to_string(),
+            ));
+        }
+        let if_true = self.if_true.parse()?;
+        let if_false = self.if_false.parse()?;
+
+        Ok(Monkey::new(
+            items,
+            operation,
+            ThrowCheck::new(test, if_true, if_false),
+        ))
+    }
+}
+
+/// Splits the input into monkey blocks and builds a monkey from the fields `find_fields` finds in
+/// the lines of each one
+fn parse_monkeys<'a, F>(input_str: &'a str, mut find_fields: F) -> Result<MonkeyPack, AocError>
+where
+    F: FnMut(&[&'a str]) -> Result<MonkeyFields<'a>, AocError>,
+{
+    let monkey_strs = split_records(input_str);
+    if monkey_strs.is_empty() {
+        return Err(AocError::Parse(
+            "There are no monkeys in the input".to_string(),
+        ));
+    }
+
+    let mut monkeys = Vec::new();
+    for (monkey_index, monkey_str) in monkey_strs.into_iter().enumerate() {
+        let monkey_lines: Vec<_> = monkey_str.lines().skip(1).collect();
+        if monkey_lines.len() < MONKEY_FIELDS {
+            return Err(AocError::Parse(format!(
+                "Monkey {} has {} of its {} fields",
+                monkey_index,
+                monkey_lines.len(),
+                MONKEY_FIELDS
+            )));
+        }
+
+        monkeys.push(find_fields(&monkey_lines)?.into_monkey()?);
+    }
+
+    MonkeyPack::new(monkeys)
+}
+
+/// Finds each field of a monkey after the fixed text introducing it
+fn find_fields<'a>(monkey_lines: &[&'a str]) -> Result<MonkeyFields<'a>, AocError> {
+    let field = |i: usize, prefix: &str, name: &'static str| {
+        monkey_lines[i]
+            .trim()
+            .strip_prefix(prefix)
+            .map(str::trim)
+            .ok_or(AocError::MissingField(name))
+    };
+
+    Ok(MonkeyFields {
+        items: field(0, "Starting items:", "starting items")?,
+        operation: field(1, "Operation: new =", "operation")?,
+        test: field(2, "Test: divisible by", "test")?,
+        if_true: field(3, "If true: throw to monkey", "if true case")?,
+        if_false: field(4, "If false: throw to monkey", "if false case")?,
+    })
+}
+
+pub fn parse_str(input_str: &str) -> Result<MonkeyPack, AocError> {
+    parse_monkeys(input_str, find_fields)
+}
+
+pub fn parse_input<T: AsRef<Path>>(filename: T) -> Result<MonkeyPack, AocError> {
+    parse_str(&read_to_string_trimmed(filename)?)
+}
+
+pub const INPUT_PATH: &str = "inputs/day11.in";
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    const INPUT_PATH: &'static str = INPUT_PATH;
+    const PART1_LABEL: &'static str = "Monkey business";
+    const PART2_LABEL: &'static str = "Monkey business";
+
+    type Input = MonkeyPack;
+
+    fn parse(s: &str) -> Result<Self::Input, AocError> {
+        parse_str(s.trim())
+    }
+
+    fn part1(monkey_pack: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part1(monkey_pack.clone())?.into())
+    }
+
+    fn part2(monkey_pack: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part2(monkey_pack.clone())?.into())
+    }
+}
+
+/// Solves both parts against the committed input
+pub fn run() -> Result<DayResult, AocError> {
+    solve::<Day11, _>(INPUT_PATH)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("../../../inputs/day11_test.in");
+
+    #[test]
+    fn part1_sample() {
+        let monkey_pack = parse_str(SAMPLE).unwrap();
+        assert_eq!(part1(monkey_pack).unwrap(), 10605);
+    }
+
+    #[test]
+    fn part2_sample() {
+        let monkey_pack = parse_str(SAMPLE).unwrap();
+        assert_eq!(part2(monkey_pack).unwrap(), 2713310158);
+    }
+
+    #[test]
+    fn first_round_items() {
+        let mut monkey_pack = parse_str(SAMPLE).unwrap();
+        monkey_pack
+            .run_rounds(1, Some(PART1_WORRY_DIVISOR))
+            .unwrap();
+
+        let items: Vec<_> = monkey_pack
+            .monkeys
+            .iter()
+            .map(|m| m.items.clone())
+            .collect();
+        assert_eq!(
+            items,
+            vec![
+                vec![20, 23, 27, 26],
+                vec![2080, 25, 167, 207, 401, 1046],
+                vec![],
+                vec![],
+            ]
+        );
+
+        // Running the remaining rounds gives the part 1 answer
+        monkey_pack
+            .run_rounds(PART1_ROUNDS - 1, Some(PART1_WORRY_DIVISOR))
+            .unwrap();
+        assert_eq!(monkey_pack.monkey_business().unwrap(), 10605);
+    }
+
+    #[test]
+    fn big_integers_agree_with_lcm() {
+        let mut monkey_pack = parse_str(SAMPLE).unwrap();
+        let mut big_monkey_pack = BigMonkeyPack::from(&monkey_pack);
+
+        // Compare after every round, until the numbers get too large to keep going
+        for _ in 0..20 {
+            monkey_pack.run_one_round(None).unwrap();
+            big_monkey_pack.run_one_round();
+            assert_eq!(
+                big_monkey_pack.inspect_counts(),
+                monkey_pack.inspect_counts()
+            );
+        }
+        assert_eq!(big_monkey_pack.inspect_counts(), vec![99, 97, 8, 103]);
+    }
+
+    #[test]
+    fn compound_operations() {
+        let input = SAMPLE.replacen("new = old * 19", "new = (old + 2) * 19", 1);
+        let monkey_pack = parse_str(&input).unwrap();
+        assert_eq!(
+            monkey_pack.monkeys[0].inspect_op,
+            Expr::Mul(
+                Box::new(Expr::Add(Box::new(Expr::Old), Box::new(Expr::Num(2)))),
+                Box::new(Expr::Num(19))
+            )
+        );
+        assert_eq!(monkey_pack.monkeys[0].inspect_op.eval(&79), 1539);
+
+        let input = SAMPLE.replacen("new = old * 19", "new = old - 19", 1);
+        assert!(matches!(parse_str(&input), Err(AocError::Parse(_))));
+    }
+
+    #[test]
+    fn crlf_input() {
+        let crlf_sample = SAMPLE.replace('\n', "\r\n");
+        assert_eq!(parse_str(&crlf_sample).unwrap(), parse_str(SAMPLE).unwrap());
+    }
+
+    #[test]
+    fn truncated_monkey() {
+        let truncated = SAMPLE.replacen("    If false: throw to monkey 0\n", "", 1);
+        assert_eq!(
+            parse_str(&truncated).err().unwrap().to_string(),
+            "Parse error: Monkey 1 has 4 of its 5 fields"
+        );
+        assert!(parse_str("Monkey 0:").is_err());
+        assert!(parse_str("").is_err());
+    }
+
+    #[test]
+    fn invalid_tests() {
+        let input = SAMPLE.replacen("divisible by 23", "divisible by 0", 1);
+        assert!(matches!(parse_str(&input), Err(AocError::Parse(_))));
+
+        // Divisors that are each fine, but whose LCM doesn't fit
+        let input = SAMPLE
+            .replacen(
+                "divisible by 23",
+                &format!("divisible by {}", usize::MAX - 1),
+                1,
+            )
+            .replacen(
+                "divisible by 19",
+                &format!("divisible by {}", usize::MAX - 2),
+                1,
+            );
+        assert!(matches!(parse_str(&input), Err(AocError::Parse(_))));
+    }
+
+    #[test]
+    fn worry_overflow() {
+        // Dividing keeps worry levels from being reduced, so squaring them soon overflows
+        let monkey_pack = parse_str(SAMPLE).unwrap();
+        assert!(matches!(
+            monkey_business_after(monkey_pack.clone(), 1000, Some(PART1_WORRY_DIVISOR)),
+            Err(AocError::Overflow(_))
+        ));
+        assert_eq!(part2(monkey_pack).unwrap(), 2713310158);
+    }
+
+    #[test]
+    fn invalid_throw_targets() {
+        let input = SAMPLE.replacen(
+            "If false: throw to monkey 3",
+            "If false: throw to monkey 9",
+            1,
+        );
+        assert_eq!(
+            parse_str(&input).err().unwrap().to_string(),
+            "Parse error: Monkey 0 throws to monkey 9, but it can only throw to another of the 4 monkeys"
+        );
+
+        let input = SAMPLE.replacen(
+            "If true: throw to monkey 2",
+            "If true: throw to monkey 0",
+            1,
+        );
+        assert!(matches!(parse_str(&input), Err(AocError::Parse(_))));
+    }
+
+    #[test]
+    fn round_logs() {
+        let mut monkey_pack = parse_str(SAMPLE).unwrap();
+
+        // Monkey 0 inspects 79 and 98 first, ending up at 500 and 620 and throwing both to 3
+        let round_log = monkey_pack
+            .run_one_round_logged(Some(PART1_WORRY_DIVISOR))
+            .unwrap();
+        assert_eq!(round_log.throws[0], vec![(500, 3), (620, 3)]);
+
+        let mut logged_counts: Vec<_> = round_log.throws.iter().map(Vec::len).collect();
+        for _ in 1..PART1_ROUNDS {
+            let round_log = monkey_pack
+                .run_one_round_logged(Some(PART1_WORRY_DIVISOR))
+                .unwrap();
+            for (count, throws) in logged_counts.iter_mut().zip(&round_log.throws) {
+                *count += throws.len();
+            }
+        }
+        assert_eq!(logged_counts, monkey_pack.inspect_counts());
+        assert_eq!(monkey_pack.monkey_business().unwrap(), 10605);
+    }
+
+    #[test]
+    fn divided_levels_are_not_reduced() {
+        let input = "Monkey 0:\n\
+                     Starting items: 10\n\
+                     Operation: new = old * 10\n\
+                     Test: divisible by 7\n\
+                     If true: throw to monkey 1\n\
+                     If false: throw to monkey 1\n\
+                     \n\
+                     Monkey 1:\n\
+                     Starting items: 1\n\
+                     Operation: new = old + 0\n\
+                     Test: divisible by 5\n\
+                     If true: throw to monkey 0\n\
+                     If false: throw to monkey 0";
+
+        // In the second round, 11 becomes 110 / 3 = 36 for monkey 1, which is 12 once divided
+        // again. Had 36 been reduced modulo 35 first, monkey 0 would get 1 / 3 = 0 instead
+        let mut monkey_pack = parse_str(input).unwrap();
+        monkey_pack
+            .run_rounds(2, Some(PART1_WORRY_DIVISOR))
+            .unwrap();
+        assert_eq!(monkey_pack.monkeys[0].items, vec![0, 12]);
+
+        // Other divisors, or none at all
+        let mut monkey_pack = parse_str(input).unwrap();
+        monkey_pack.run_rounds(1, NonZeroUsize::new(2)).unwrap();
+        assert_eq!(monkey_pack.monkeys[0].items, vec![0, 25]);
+
+        let mut monkey_pack = parse_str(input).unwrap();
+        monkey_pack.run_rounds(1, None).unwrap();
+        assert_eq!(monkey_pack.monkeys[0].items, vec![1, 30]);
+    }
+
+    #[test]
+    fn final_item_distribution() {
+        let mut monkey_pack = parse_str(SAMPLE).unwrap();
+        monkey_pack
+            .run_rounds(PART1_ROUNDS, Some(PART1_WORRY_DIVISOR))
+            .unwrap();
+        assert_eq!(monkey_pack.items_per_monkey(), vec![5, 5, 0, 0]);
+        assert_eq!(monkey_pack.most_loaded_monkey(), 0);
+        assert_eq!(monkey_pack.monkeys[1].items, vec![245, 93, 53, 199, 115]);
+
+        // Before any round, monkey 1 holds the most items
+        let monkey_pack = parse_str(SAMPLE).unwrap();
+        assert_eq!(monkey_pack.items_per_monkey(), vec![2, 4, 3, 1]);
+        assert_eq!(monkey_pack.most_loaded_monkey(), 1);
+    }
+
+    #[test]
+    fn top_k_monkey_business() {
+        let mut monkey_pack = parse_str(SAMPLE).unwrap();
+        monkey_pack
+            .run_rounds(PART1_ROUNDS, Some(PART1_WORRY_DIVISOR))
+            .unwrap();
+
+        // Inspection counts are 101, 95, 7 and 105
+        assert_eq!(monkey_pack.monkey_business_top_k(2).unwrap(), 10605);
+        assert_eq!(monkey_pack.monkey_business_top_k(1).unwrap(), 105);
+        assert_eq!(
+            monkey_pack.monkey_business_top_k(3).unwrap(),
+            105 * 101 * 95
+        );
+        assert_eq!(
+            monkey_pack.monkey_business_top_k(4).unwrap(),
+            105 * 101 * 95 * 7
+        );
+        assert_eq!(
+            monkey_pack.monkey_business_top_k(10).unwrap(),
+            105 * 101 * 95 * 7
+        );
+    }
+
+    #[test]
+    fn monkey_business_overflow() {
+        let mut monkey_pack = parse_str(SAMPLE).unwrap();
+        monkey_pack.monkeys[0].inspect_count = 1 << 40;
+        monkey_pack.monkeys[1].inspect_count = 1 << 30;
+        assert!(matches!(
+            monkey_pack.monkey_business(),
+            Err(AocError::Overflow(_))
+        ));
+        // Only the two largest counts are multiplied
+        monkey_pack.monkeys[1].inspect_count = 1;
+        assert_eq!(monkey_pack.monkey_business().unwrap(), 1 << 40);
+        assert_eq!(monkey_pack.monkey_business_top_k(0).unwrap(), 1);
+    }
+
+    #[test]
+    fn rounds_progress() {
+        let mut monkey_pack = parse_str(SAMPLE).unwrap();
+        let mut rounds_done = Vec::new();
+        monkey_pack
+            .run_rounds_with_progress(3, Some(PART1_WORRY_DIVISOR), |round| {
+                rounds_done.push(round)
+            })
+            .unwrap();
+        assert_eq!(rounds_done, vec![1, 2, 3]);
+
+        let mut same_pack = parse_str(SAMPLE).unwrap();
+        same_pack.run_rounds(3, Some(PART1_WORRY_DIVISOR)).unwrap();
+        assert_eq!(monkey_pack.inspect_counts(), same_pack.inspect_counts());
+    }
+
+    #[test]
+    fn empty_input() {
+        assert!(matches!(Day11::parse(""), Err(AocError::Parse(_))));
+    }
+}
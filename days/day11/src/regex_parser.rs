@@ -0,0 +1,58 @@
+use aoc_common::AocError;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::{parse_monkeys, MonkeyFields, MonkeyPack};
+
+static ITEMS_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"Starting\s+items:\s*((?:\d+(?:,\s*)?)+)").expect("Error compiling regex")
+});
+static OPERATION_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"Operation:\s*new\s*=\s*(.+)").expect("Error compiling regex"));
+static TEST_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"Test:\s*divisible\s+by\s+(\d+)").expect("Error compiling regex"));
+static IF_TRUE_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"If\s+true:\s*throw\s+to\s+monkey\s+(\d+)").expect("Error compiling regex")
+});
+static IF_FALSE_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"If\s+false:\s*throw\s+to\s+monkey\s+(\d+)").expect("Error compiling regex")
+});
+
+/// Text of the first group `re` captures in a line
+fn capture<'a>(re: &Regex, line: &'a str, name: &'static str) -> Result<&'a str, AocError> {
+    re.captures(line)
+        .and_then(|cap| cap.get(1))
+        .map(|m| m.as_str())
+        .ok_or(AocError::MissingField(name))
+}
+
+/// Same as `parse_str`, but finding each field with a regex, which is more lenient about spacing
+/// and slower
+pub fn parse_str_regex(input_str: &str) -> Result<MonkeyPack, AocError> {
+    parse_monkeys(input_str, |monkey_lines| {
+        Ok(MonkeyFields {
+            items: capture(&ITEMS_RE, monkey_lines[0], "starting items")?,
+            operation: capture(&OPERATION_RE, monkey_lines[1], "operation")?,
+            test: capture(&TEST_RE, monkey_lines[2], "test")?,
+            if_true: capture(&IF_TRUE_RE, monkey_lines[3], "if true case")?,
+            if_false: capture(&IF_FALSE_RE, monkey_lines[4], "if false case")?,
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::parse_str;
+
+    const SAMPLE: &str = include_str!("../../../inputs/day11_test.in");
+    const INPUT: &str = include_str!("../../../inputs/day11.in");
+
+    #[test]
+    fn same_as_plain_parser() {
+        for input in [SAMPLE, INPUT.trim()] {
+            assert_eq!(parse_str_regex(input).unwrap(), parse_str(input).unwrap());
+        }
+    }
+}
@@ -0,0 +1,470 @@
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::Path;
+use std::str::FromStr;
+
+use aoc_common::{
+    encode_ppm, read_to_string, solve, Answer, AocError, DayResult, Grid, PhaseTimer, Point,
+    Solution,
+};
+
+#[derive(Debug)]
+pub struct HeightMap {
+    heights: Grid<u32>,
+    start: Point<usize>,
+    end: Point<usize>,
+    diagonal: bool,
+}
+
+impl HeightMap {
+    fn new(heights: Grid<u32>, start: Point<usize>, end: Point<usize>) -> Self {
+        HeightMap {
+            heights,
+            start,
+            end,
+            diagonal: false,
+        }
+    }
+
+    /// Lets the searches also move diagonally, still climbing at most one height per step
+    pub fn with_diagonal(mut self, diagonal: bool) -> Self {
+        self.diagonal = diagonal;
+        self
+    }
+
+    fn neighbours(&self, position: Point<usize>) -> Box<dyn Iterator<Item = Point<usize>> + '_> {
+        let to_point = |(row, col)| Point::new(col, row);
+        if self.diagonal {
+            Box::new(
+                self.heights
+                    .neighbors8(position.y, position.x)
+                    .map(to_point),
+            )
+        } else {
+            Box::new(
+                self.heights
+                    .neighbors4(position.y, position.x)
+                    .map(to_point),
+            )
+        }
+    }
+
+    fn get_higher_neighbours(&self, position: Point<usize>) -> Vec<Point<usize>> {
+        let pos_height = self.heights[position];
+        self.neighbours(position)
+            .filter(|&neighbour| self.heights[neighbour] <= pos_height + 1)
+            .collect()
+    }
+
+    /// Breadth-first search from all of `sources` at once, moving to the positions given by
+    /// `neighbours`, until a position matching `is_target` is found. Returns the path to it from
+    /// the closest source, or an empty one if there's no such position within reach. `on_expand`
+    /// is called for every position taken out of the queue, and `timer` times the search and the
+    /// path rebuilding
+    fn bfs_path<S, N, T, P>(
+        &self,
+        sources: S,
+        neighbours: N,
+        is_target: T,
+        mut on_expand: P,
+        timer: &mut PhaseTimer,
+    ) -> Vec<Point<usize>>
+    where
+        S: IntoIterator<Item = Point<usize>>,
+        N: Fn(&Self, Point<usize>) -> Vec<Point<usize>>,
+        T: Fn(Point<usize>) -> bool,
+        P: FnMut(),
+    {
+        let (parents, target) = timer.time("breadth-first search", || {
+            // Every edge costs the same and every source starts at distance 0, so positions are
+            // reached in order of distance and the first time a position is seen is through a
+            // shortest path from whichever source is closest
+            let mut queue: VecDeque<_> = sources.into_iter().collect();
+            let mut parents: HashMap<_, _> = queue.iter().map(|&source| (source, None)).collect();
+            while let Some(position) = queue.pop_front() {
+                on_expand();
+                if is_target(position) {
+                    return (parents, Some(position));
+                }
+
+                for neighbour in neighbours(self, position) {
+                    if let Entry::Vacant(entry) = parents.entry(neighbour) {
+                        entry.insert(Some(position));
+                        queue.push_back(neighbour);
+                    }
+                }
+            }
+
+            (parents, None)
+        });
+
+        // Rebuild the path by reverse iterating through the positions' parents
+        timer.time("rebuilding path", || {
+            let mut path: Vec<_> =
+                std::iter::successors(target, |position| parents[position]).collect();
+            path.reverse();
+
+            path
+        })
+    }
+
+    /// Shortest path from the start to the end, both included, or an empty one if the end can't
+    /// be reached
+    pub fn shortest_path(&self) -> Vec<Point<usize>> {
+        self.shortest_path_with_progress(|| {})
+    }
+
+    /// Same as `shortest_path`, calling `on_expand` for every position the search goes through
+    pub fn shortest_path_with_progress<P: FnMut()>(&self, on_expand: P) -> Vec<Point<usize>> {
+        self.shortest_path_timed(on_expand, &mut PhaseTimer::disabled())
+    }
+
+    /// Same as `shortest_path_with_progress`, timing the phases of the search with `timer`
+    pub fn shortest_path_timed<P: FnMut()>(
+        &self,
+        on_expand: P,
+        timer: &mut PhaseTimer,
+    ) -> Vec<Point<usize>> {
+        self.bfs_path(
+            [self.start],
+            Self::get_higher_neighbours,
+            |p| p == self.end,
+            on_expand,
+            timer,
+        )
+    }
+
+    /// Shortest path from any cell of height 'a' to the end, both included, or an empty one if
+    /// the end can't be reached from any of them
+    pub fn shortest_hike_path(&self) -> Vec<Point<usize>> {
+        self.shortest_hike_path_with_progress(|| {})
+    }
+
+    /// Same as `shortest_hike_path`, calling `on_expand` for every position the search goes
+    /// through
+    pub fn shortest_hike_path_with_progress<P: FnMut()>(&self, on_expand: P) -> Vec<Point<usize>> {
+        self.shortest_hike_path_timed(on_expand, &mut PhaseTimer::disabled())
+    }
+
+    /// Same as `shortest_hike_path_with_progress`, timing the phases of the search with `timer`
+    pub fn shortest_hike_path_timed<P: FnMut()>(
+        &self,
+        on_expand: P,
+        timer: &mut PhaseTimer,
+    ) -> Vec<Point<usize>> {
+        let low_cells = (0..self.heights.height())
+            .flat_map(|row| (0..self.heights.width()).map(move |col| Point::new(col, row)))
+            .filter(|&position| self.heights[position] == 0);
+
+        self.bfs_path(
+            low_cells,
+            Self::get_higher_neighbours,
+            |p| p == self.end,
+            on_expand,
+            timer,
+        )
+    }
+
+    /// Amount of cells in the map, which bounds how many positions a search goes through
+    pub fn cell_count(&self) -> usize {
+        self.heights.width() * self.heights.height()
+    }
+
+    /// Draws the map with an arrow on each cell of the path pointing to the next one, like the
+    /// puzzle's visualization. Diagonal steps are drawn as `↖`, `↗`, `↙` and `↘`, and cells off
+    /// the path as `.`
+    pub fn path_as_string(&self, path: &[Point<usize>]) -> String {
+        let mut cells = vec![vec!['.'; self.heights.width()]; self.heights.height()];
+        for step in path.windows(2) {
+            let (from, to) = (step[0], step[1]);
+            cells[from.y][from.x] = match (to.x.cmp(&from.x), to.y.cmp(&from.y)) {
+                (Ordering::Greater, Ordering::Equal) => '>',
+                (Ordering::Less, Ordering::Equal) => '<',
+                (Ordering::Equal, Ordering::Less) => '^',
+                (Ordering::Equal, _) => 'v',
+                (Ordering::Less, Ordering::Less) => '↖',
+                (Ordering::Greater, Ordering::Less) => '↗',
+                (Ordering::Less, Ordering::Greater) => '↙',
+                (Ordering::Greater, Ordering::Greater) => '↘',
+            };
+        }
+        cells[self.end.y][self.end.x] = 'E';
+
+        cells
+            .into_iter()
+            .map(|row| row.into_iter().collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Encodes the map as a binary PPM image, one pixel per cell, with heights going from dark
+    /// blue for 'a' to white for 'z' and the cells of `path` drawn in red
+    pub fn to_ppm(&self, path: &[Point<usize>]) -> Vec<u8> {
+        let path: HashSet<_> = path.iter().collect();
+        encode_ppm(
+            self.heights.width(),
+            self.heights.height(),
+            1,
+            |row, col| {
+                let position = Point::new(col, row);
+                if path.contains(&position) {
+                    return [255, 0, 0];
+                }
+
+                let shade = (self.heights[position] * 255 / 25) as u8;
+                [shade, shade, 128 + shade / 2]
+            },
+        )
+    }
+
+    /// Fewest steps to the end from any cell of height 'a', the length of `shortest_hike_path`.
+    /// `None` if the end can't be reached from any of them
+    pub fn shortest_from_any_low(&self) -> Option<usize> {
+        steps(&self.shortest_hike_path())
+    }
+}
+
+/// Parses a map of heights, which also holds where the 'S' and 'E' markers are
+impl FromStr for HeightMap {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_str(s)
+    }
+}
+
+pub fn parse_str(input: &str) -> Result<HeightMap, AocError> {
+    let mut starts = Vec::new();
+    let mut ends = Vec::new();
+    let heights = Grid::from_str_with(input, |(row, col), c| {
+        let position = Point::new(col, row);
+        match c {
+            'S' => {
+                starts.push(position);
+                Ok(0)
+            }
+            'E' => {
+                ends.push(position);
+                Ok(25)
+            }
+            'a'..='z' => Ok(c as u32 - 'a' as u32),
+            _ => Err(AocError::Parse(format!(
+                "Line {}: invalid height '{}'",
+                row + 1,
+                c
+            ))),
+        }
+    })?;
+
+    // There has to be exactly one of each marker, or the paths between them are meaningless
+    let [start] = starts[..] else {
+        return Err(AocError::Parse(format!(
+            "Expected exactly one start 'S', found {}",
+            starts.len()
+        )));
+    };
+    let [end] = ends[..] else {
+        return Err(AocError::Parse(format!(
+            "Expected exactly one end 'E', found {}",
+            ends.len()
+        )));
+    };
+
+    Ok(HeightMap::new(heights, start, end))
+}
+
+pub fn parse_input<T: AsRef<Path>>(filename: T) -> Result<HeightMap, AocError> {
+    parse_str(&read_to_string(filename)?)
+}
+
+/// Number of steps along a path, or `None` if it's empty because there was no path
+fn steps(path: &[Point<usize>]) -> Option<usize> {
+    path.len().checked_sub(1)
+}
+
+/// Number of steps along a path, failing if it's empty because there was no path
+pub fn path_steps(path: &[Point<usize>]) -> Result<usize, AocError> {
+    steps(path).ok_or_else(|| AocError::Parse("No path to the end in the height map".to_string()))
+}
+
+pub fn part1(height_map: &HeightMap) -> Option<usize> {
+    steps(&height_map.shortest_path())
+}
+
+pub fn part2(height_map: &HeightMap) -> Option<usize> {
+    height_map.shortest_from_any_low()
+}
+
+pub const INPUT_PATH: &str = "inputs/day12.in";
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+    const INPUT_PATH: &'static str = INPUT_PATH;
+    const PART1_LABEL: &'static str = "Minimum steps to reach the end";
+    const PART2_LABEL: &'static str = "Shortest hike path length";
+
+    type Input = HeightMap;
+
+    fn parse(s: &str) -> Result<Self::Input, AocError> {
+        s.parse()
+    }
+
+    fn part1(height_map: &Self::Input) -> Result<Answer, AocError> {
+        Ok(path_steps(&height_map.shortest_path())?.into())
+    }
+
+    fn part2(height_map: &Self::Input) -> Result<Answer, AocError> {
+        Ok(path_steps(&height_map.shortest_hike_path())?.into())
+    }
+}
+
+/// Solves both parts against the committed input
+pub fn run() -> Result<DayResult, AocError> {
+    solve::<Day12, _>(INPUT_PATH)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("../../../inputs/day12_test.in");
+
+    #[test]
+    fn part1_sample() {
+        let height_map = parse_str(SAMPLE).unwrap();
+        assert_eq!(part1(&height_map), Some(31));
+    }
+
+    #[test]
+    fn part2_sample() {
+        let height_map = parse_str(SAMPLE).unwrap();
+        assert_eq!(part2(&height_map), Some(29));
+    }
+
+    #[test]
+    fn parse_with_from_str() {
+        let height_map: HeightMap = SAMPLE.parse().unwrap();
+        assert_eq!(height_map.start, Point::new(0, 0));
+        assert_eq!(height_map.end, Point::new(5, 2));
+        assert_eq!(part1(&height_map), Some(31));
+        assert!("SaE\nab".parse::<HeightMap>().is_err());
+    }
+
+    #[test]
+    fn multi_source_matches_part2() {
+        let height_map = parse_str(SAMPLE).unwrap();
+        assert_eq!(height_map.shortest_from_any_low(), Some(29));
+        assert_eq!(height_map.shortest_from_any_low(), part2(&height_map));
+    }
+
+    #[test]
+    fn paths() {
+        let height_map = parse_str(SAMPLE).unwrap();
+
+        let path = height_map.shortest_path();
+        assert_eq!(path.first(), Some(&Point::new(0, 0)));
+        assert_eq!(path.last(), Some(&Point::new(5, 2)));
+        assert!(path
+            .windows(2)
+            .all(|step| step[0].x.abs_diff(step[1].x) + step[0].y.abs_diff(step[1].y) == 1));
+
+        let hike_path = height_map.shortest_hike_path();
+        assert_eq!(height_map.heights[hike_path[0]], 0);
+        assert_eq!(hike_path.last(), Some(&Point::new(5, 2)));
+    }
+
+    #[test]
+    fn path_drawing() {
+        let height_map = parse_str(SAMPLE).unwrap();
+        let drawing = height_map.path_as_string(&height_map.shortest_path());
+
+        assert_eq!(drawing.lines().count(), 5);
+        assert_eq!(drawing.matches(['>', '<', '^', 'v']).count(), 31);
+        assert_eq!(drawing.matches('E').count(), 1);
+    }
+
+    #[test]
+    fn no_path() {
+        let height_map = parse_str("SbE").unwrap();
+        assert!(height_map.shortest_path().is_empty());
+        assert!(height_map.shortest_hike_path().is_empty());
+        assert_eq!(part1(&height_map), None);
+        assert_eq!(part2(&height_map), None);
+        assert_eq!(height_map.shortest_from_any_low(), None);
+        assert!(matches!(Day12::part1(&height_map), Err(AocError::Parse(_))));
+        assert!(matches!(Day12::part2(&height_map), Err(AocError::Parse(_))));
+    }
+
+    #[test]
+    fn invalid_maps() {
+        for map in [
+            "SabE\nabcE",
+            "abcE",
+            "SabS\nabcE",
+            "Sab1\nabcE",
+            "SabB\nabcE",
+        ] {
+            assert!(matches!(parse_str(map), Err(AocError::Parse(_))), "{}", map);
+        }
+    }
+
+    #[test]
+    fn diagonal_moves() {
+        let height_map = parse_str(SAMPLE).unwrap().with_diagonal(true);
+        // Cutting corners shortens both routes compared to the 31 and 29 steps of part 1 and 2
+        assert_eq!(part1(&height_map), Some(27));
+        assert_eq!(part2(&height_map), Some(26));
+        assert_eq!(height_map.shortest_from_any_low(), Some(26));
+
+        let drawing = height_map.path_as_string(&height_map.shortest_path());
+        assert_eq!(
+            drawing
+                .chars()
+                .filter(|c| !matches!(c, '.' | 'E' | '\n'))
+                .count(),
+            27
+        );
+    }
+
+    #[test]
+    fn ppm_image() {
+        let height_map = parse_str(SAMPLE).unwrap();
+        let path = height_map.shortest_path();
+        let ppm = height_map.to_ppm(&path);
+
+        let header = b"P6\n8 5\n255\n";
+        assert_eq!(&ppm[..header.len()], header);
+        assert_eq!(ppm.len(), header.len() + 8 * 5 * 3);
+
+        // The path starts at the top left corner, while the 'a' two cells below it is off the path
+        let pixels = &ppm[header.len()..];
+        assert_eq!(pixels[..3], [255, 0, 0]);
+        assert_eq!(pixels[2 * 8 * 3..2 * 8 * 3 + 3], [0, 0, 128]);
+    }
+
+    #[test]
+    fn search_progress() {
+        let height_map = parse_str(SAMPLE).unwrap();
+        let mut expanded = 0;
+        let path = height_map.shortest_path_with_progress(|| expanded += 1);
+
+        assert_eq!(path, height_map.shortest_path());
+        assert!(expanded > 0 && expanded <= height_map.cell_count());
+        assert_eq!(path_steps(&path).unwrap(), 31);
+        assert!(path_steps(&[]).is_err());
+
+        let mut timer = PhaseTimer::new(true);
+        let hike_path = height_map.shortest_hike_path_timed(|| {}, &mut timer);
+        assert_eq!(hike_path, height_map.shortest_hike_path());
+        let names: Vec<_> = timer.phases().iter().map(|(name, _)| *name).collect();
+        assert_eq!(names, ["breadth-first search", "rebuilding path"]);
+    }
+
+    #[test]
+    fn empty_input() {
+        assert!(matches!(Day12::parse(""), Err(AocError::Parse(_))));
+    }
+}
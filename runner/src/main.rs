@@ -0,0 +1,252 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::fs;
+use std::panic;
+use std::time::{Duration, Instant};
+
+use aoc_common::{format_millis, AocError, Args, DayResult};
+use rayon::prelude::*;
+
+type DayRun = fn() -> Result<DayResult, AocError>;
+
+/// Result of running a day, with how long the whole run took
+type DayOutcome = (u32, Result<DayResult, DayFailure>, Duration);
+
+/// Why a day gave no answers
+#[derive(Debug)]
+enum DayFailure {
+    Error(AocError),
+    Panic(String),
+}
+
+impl Display for DayFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DayFailure::Error(e) => write!(f, "{}", e),
+            DayFailure::Panic(msg) => write!(f, "panicked: {}", msg),
+        }
+    }
+}
+
+/// Runs a day, turning a panic into a failure so the other days still run
+fn run_day(run: DayRun) -> Result<DayResult, DayFailure> {
+    match panic::catch_unwind(run) {
+        Ok(result) => result.map_err(DayFailure::Error),
+        Err(payload) => {
+            let msg = payload
+                .downcast_ref::<&str>()
+                .map(|msg| msg.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown cause".to_string());
+            Err(DayFailure::Panic(msg))
+        }
+    }
+}
+
+const DAYS: [(u32, DayRun); 12] = [
+    (1, day01::run),
+    (2, day02::run),
+    (3, day03::run),
+    (4, day04::run),
+    (5, day05::run),
+    (6, day06::run),
+    (7, day07::run),
+    (8, day08::run),
+    (9, day09::run),
+    (10, day10::run),
+    (11, day11::run),
+    (12, day12::run),
+];
+
+struct Row {
+    day: u32,
+    part1: String,
+    part2: String,
+    time: String,
+}
+
+/// Answer as a markdown table cell, with multi-line answers kept on their own lines
+fn markdown_cell(answer: &str) -> String {
+    let answer = answer.replace('|', "\\|");
+    if answer.contains('\n') {
+        format!(
+            "<code>{}</code>",
+            answer.lines().collect::<Vec<_>>().join("<br>")
+        )
+    } else {
+        answer
+    }
+}
+
+/// Markdown table of every day's answers and timings, in milliseconds
+fn markdown_report(results: &[DayOutcome]) -> String {
+    let mut report = String::from(
+        "| Day | Part 1 | Part 2 | Parse ms | Part 1 ms | Part 2 ms |\n\
+         |----:|--------|--------|---------:|----------:|----------:|\n",
+    );
+    for (day, result, _) in results {
+        let row = match result {
+            Ok(day_result) => format!(
+                "| {} | {} | {} | {:.3} | {:.3} | {:.3} |\n",
+                day,
+                markdown_cell(&day_result.part1.to_string()),
+                markdown_cell(&day_result.part2.to_string()),
+                format_millis(day_result.parse_time),
+                format_millis(day_result.part1_time),
+                format_millis(day_result.part2_time)
+            ),
+            Err(_) => format!("| {} | FAILED | FAILED | - | - | - |\n", day),
+        };
+        report.push_str(&row);
+    }
+
+    report
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args = Args::from_env()?;
+
+    // Run every day concurrently, timing each one and the whole batch
+    let t = Instant::now();
+    let results: Vec<DayOutcome> = DAYS
+        .par_iter()
+        .map(|&(day, run)| {
+            let t = Instant::now();
+            let result = run_day(run);
+            (day, result, t.elapsed())
+        })
+        .collect();
+    let wall_time = t.elapsed();
+
+    let mut rows = Vec::new();
+    let mut notes = Vec::new();
+    let mut summed_time = Duration::ZERO;
+    for &(day, ref result, time) in &results {
+        summed_time += time;
+
+        let (part1, part2) = match result {
+            Ok(day_result) => (day_result.part1.to_string(), day_result.part2.to_string()),
+            Err(e) => {
+                notes.push(format!("Day {} failed: {}", day, e));
+                rows.push(Row {
+                    day,
+                    part1: "FAILED".to_string(),
+                    part2: "FAILED".to_string(),
+                    time: "-".to_string(),
+                });
+                continue;
+            }
+        };
+
+        // Multi-line answers (like day 10's CRT) don't fit in the table, print them after it
+        let mut cell = |part: u32, answer: String| {
+            if answer.contains('\n') {
+                notes.push(format!("Day {} part {}:\n{}", day, part, answer));
+                "(see below)".to_string()
+            } else {
+                answer
+            }
+        };
+        let part1 = cell(1, part1);
+        let part2 = cell(2, part2);
+
+        rows.push(Row {
+            day,
+            part1,
+            part2,
+            time: format!("{:.6}", format_millis(time)),
+        });
+    }
+
+    // Size the columns to fit the longest cell
+    let part1_width = rows.iter().map(|r| r.part1.len()).max().unwrap_or(0).max(6);
+    let part2_width = rows.iter().map(|r| r.part2.len()).max().unwrap_or(0).max(6);
+    let time_width = rows.iter().map(|r| r.time.len()).max().unwrap_or(0).max(9);
+
+    println!(
+        "Day | {:<part1_width$} | {:<part2_width$} | {:>time_width$}",
+        "Part 1", "Part 2", "Time (ms)"
+    );
+    println!(
+        "----|-{}-|-{}-|-{}",
+        "-".repeat(part1_width),
+        "-".repeat(part2_width),
+        "-".repeat(time_width)
+    );
+    for row in &rows {
+        println!(
+            "{:>3} | {:<part1_width$} | {:<part2_width$} | {:>time_width$}",
+            row.day, row.part1, row.part2, row.time
+        );
+    }
+
+    println!(
+        "\nTotal wall-clock time: {:.6}ms (sum of per-day times: {:.6}ms)",
+        format_millis(wall_time),
+        format_millis(summed_time)
+    );
+
+    for note in notes {
+        println!("\n{}", note);
+    }
+
+    // Write the results as markdown, if requested
+    if let Some(report_path) = args.option("--report") {
+        fs::write(report_path, markdown_report(&results))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_rows() {
+        let day_result = |part1: &str, part2: &str| DayResult {
+            part1: part1.into(),
+            part2: part2.into(),
+            parse_time: Duration::from_micros(1500),
+            part1_time: Duration::from_millis(2),
+            part2_time: Duration::from_millis(3),
+        };
+        let results = vec![
+            (1, Ok(day_result("24000", "45000")), Duration::ZERO),
+            (
+                2,
+                Err(DayFailure::Error(AocError::Parse("bad input".to_string()))),
+                Duration::ZERO,
+            ),
+            (10, Ok(day_result("13140", "##..\n#..#")), Duration::ZERO),
+        ];
+        let report = markdown_report(&results);
+
+        // A header, its separator and one row per day, each with the six columns
+        let rows: Vec<Vec<_>> = report
+            .lines()
+            .map(|line| line.trim_matches('|').split('|').map(str::trim).collect())
+            .collect();
+        assert_eq!(rows.len(), 2 + results.len());
+        assert!(rows.iter().all(|row| row.len() == 6));
+
+        assert_eq!(rows[2], ["1", "24000", "45000", "1.500", "2.000", "3.000"]);
+        assert_eq!(rows[3][1], "FAILED");
+        assert_eq!(rows[4][2], "<code>##..<br>#..#</code>");
+    }
+
+    #[test]
+    fn panicking_day() {
+        fn panics() -> Result<DayResult, AocError> {
+            panic!("Unknown command cp")
+        }
+
+        let failure = run_day(panics).unwrap_err();
+        assert_eq!(failure.to_string(), "panicked: Unknown command cp");
+
+        fn fails() -> Result<DayResult, AocError> {
+            Err(AocError::MissingField("marker"))
+        }
+        assert!(matches!(run_day(fails), Err(DayFailure::Error(_))));
+    }
+}
@@ -0,0 +1,43 @@
+use std::path::Path;
+
+use aoc_common::{solve, Solution};
+
+/// Solves a day against its committed input, found from the workspace root so the test doesn't
+/// depend on the working directory. Days whose input isn't committed are skipped
+fn check_day<S: Solution>() {
+    let input_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(S::INPUT_PATH);
+    if !input_path.exists() {
+        eprintln!(
+            "Skipping day {}, '{}' is missing",
+            S::DAY,
+            input_path.display()
+        );
+        return;
+    }
+
+    let day_result =
+        solve::<S, _>(&input_path).unwrap_or_else(|e| panic!("Day {} failed: {}", S::DAY, e));
+    assert!(
+        !day_result.part1.to_string().is_empty() && !day_result.part2.to_string().is_empty(),
+        "Day {} gave an empty answer",
+        S::DAY
+    );
+}
+
+#[test]
+fn every_day_runs() {
+    check_day::<day01::Day01>();
+    check_day::<day02::Day02>();
+    check_day::<day03::Day03>();
+    check_day::<day04::Day04>();
+    check_day::<day05::Day05>();
+    check_day::<day06::Day06>();
+    check_day::<day07::Day07>();
+    check_day::<day08::Day08>();
+    check_day::<day09::Day09>();
+    check_day::<day10::Day10>();
+    check_day::<day11::Day11>();
+    check_day::<day12::Day12>();
+}
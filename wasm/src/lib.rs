@@ -0,0 +1,95 @@
+use aoc_common::{solve_str, AocError};
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+/// Answers of a day, as handed over to JavaScript
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct Answers {
+    pub day: u32,
+    pub part1: String,
+    pub part2: String,
+}
+
+/// Solves both parts of a day from its input text
+pub fn solve_day_answers(day: u32, input: &str) -> Result<Answers, AocError> {
+    let (part1, part2) = match day {
+        1 => solve_str::<day01::Day01>(input),
+        2 => solve_str::<day02::Day02>(input),
+        3 => solve_str::<day03::Day03>(input),
+        4 => solve_str::<day04::Day04>(input),
+        5 => solve_str::<day05::Day05>(input),
+        6 => solve_str::<day06::Day06>(input),
+        7 => solve_str::<day07::Day07>(input),
+        8 => solve_str::<day08::Day08>(input),
+        9 => solve_str::<day09::Day09>(input),
+        10 => solve_str::<day10::Day10>(input),
+        11 => solve_str::<day11::Day11>(input),
+        12 => solve_str::<day12::Day12>(input),
+        _ => Err(AocError::Parse(format!("Day {} isn't solved yet", day))),
+    }?;
+
+    Ok(Answers { day, part1, part2 })
+}
+
+/// Solves both parts of a day from its input text, returning an object like
+/// `{ day: 6, part1: "7", part2: "19" }`. Throws if the day is unknown or the input is invalid
+#[wasm_bindgen]
+pub fn solve_day(day: u32, input: &str) -> Result<JsValue, JsError> {
+    let answers = solve_day_answers(day, input).map_err(|e| JsError::new(&e.to_string()))?;
+
+    Ok(serde_wasm_bindgen::to_value(&answers)?)
+}
+
+/// Days `solve_day` knows about
+pub const SOLVED_DAYS: std::ops::RangeInclusive<u32> = 1..=12;
+
+#[cfg(test)]
+mod tests {
+    use std::panic;
+
+    use super::*;
+
+    /// Inputs no day can make sense of, aimed at the different parsers
+    const GARBAGE: &[&str] = &[
+        "",
+        "\n\n\n",
+        "garbage",
+        "1-2,three\n",
+        "$ cd ..\nfoo\n",
+        "move 1 from 0 to 9\n",
+        "Monkey 0:\n  Starting items: x\n",
+        "addx\nnoop noop\n",
+        "\u{1F980}\u{1F980}\n\u{0}\n",
+        "99999999999999999999999999\n",
+    ];
+
+    const DAY06_SAMPLE: &str = include_str!("../../inputs/day06_test.in");
+
+    #[test]
+    fn solves_day06_from_a_string() {
+        assert_eq!(
+            solve_day_answers(6, DAY06_SAMPLE).unwrap(),
+            Answers {
+                day: 6,
+                part1: "7".to_string(),
+                part2: "19".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_days_and_bad_input() {
+        assert!(matches!(solve_day_answers(26, ""), Err(AocError::Parse(_))));
+        assert!(solve_day_answers(4, "1-2,three").is_err());
+    }
+
+    #[test]
+    fn invalid_input_never_panics() {
+        for day in SOLVED_DAYS {
+            for input in GARBAGE {
+                let result = panic::catch_unwind(|| solve_day_answers(day, input));
+                assert!(result.is_ok(), "Day {} panicked on {:?}", day, input);
+            }
+        }
+    }
+}
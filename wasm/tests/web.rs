@@ -0,0 +1,23 @@
+//! Runs `solve_day` itself, which only works as a JavaScript binding on wasm32. Run with
+//! `wasm-pack test --node wasm`, or with `wasm-bindgen-test-runner` set as the cargo runner of
+//! `--target wasm32-unknown-unknown`
+#![cfg(target_arch = "wasm32")]
+
+use aoc_wasm::{solve_day, SOLVED_DAYS};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+/// A panic traps the whole module instead of throwing, so every day must return here, with
+/// answers or an error
+#[wasm_bindgen_test]
+fn invalid_input_returns() {
+    for day in SOLVED_DAYS {
+        for input in ["", "garbage\n$ cd ..\nfoo"] {
+            let _ = solve_day(day, input);
+        }
+    }
+}
+
+#[wasm_bindgen_test]
+fn unknown_day_throws() {
+    assert!(solve_day(26, "").is_err());
+}